@@ -0,0 +1,21 @@
+use aoc2025::Rng;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub rotations: usize,
+    pub max_distance: i64,
+}
+
+impl Params {
+    pub fn sized(size: usize) -> Self {
+        Params { rotations: size.max(1), max_distance: 999 }
+    }
+}
+
+/// One `L` or `R` rotation per line.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    (0..params.rotations)
+        .map(|_| format!("{}{}\n", rng.choose(&['L', 'R']), rng.int(1..=params.max_distance)))
+        .collect()
+}
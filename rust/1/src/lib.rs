@@ -0,0 +1,149 @@
+use aoc2025::num::{self, int};
+use aoc2025::parse::{self, Line};
+use aoc2025::{Example, Int, Overflow, ParseError, Records, Rng, SolveError, Solution};
+use serde::Deserialize;
+use std::num::NonZeroU32;
+
+pub mod generate;
+pub mod reference;
+
+fn rotation(line: Line) -> Result<(i64, i64), ParseError> {
+    let d = match line.text.chars().next() {
+        Some('R') => 1,
+        Some('L') => -1,
+        _ => return Err(line.error(line.text.get(..1).unwrap_or(line.text), "'L' or 'R'")),
+    };
+    let val = line.parse::<i64>(&line.text[1..], "a rotation distance")?;
+    if val < 0 {
+        return Err(line.error(&line.text[1..], "a rotation distance of at least 0"));
+    }
+    Ok((d, val))
+}
+
+/// Where the dial starts and how many numbers are on it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Dial {
+    pub start: i64,
+    pub modulus: NonZeroU32,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial { start: 50, modulus: NonZeroU32::new(100).unwrap() }
+    }
+}
+
+/// The dial and the direction (1 for right, -1 for left) and distance of each rotation.
+pub struct Safe {
+    dial: Dial,
+    rotations: Vec<(i64, i64)>,
+}
+
+fn safe(contents: &str, dial: &Dial) -> Result<Safe, ParseError> {
+    let rotations = parse::lines(contents).map(rotation).collect::<Result<_, ParseError>>()?;
+    Ok(Safe { dial: dial.clone(), rotations })
+}
+
+fn part1(safe: &Safe) -> i64 {
+    let modulus = safe.dial.modulus.get() as i64;
+    let mut dial = safe.dial.start.rem_euclid(modulus);
+    let mut count = 0;
+    for &(d, val) in &safe.rotations {
+        // Whole turns don't move the dial, and distances can be as big as an i64
+        dial = (dial + d * (val % modulus)).rem_euclid(modulus);
+        count += (dial == 0) as i64;
+    }
+    return count;
+}
+
+fn part2(safe: &Safe) -> Result<Int, Overflow> {
+    let modulus = safe.dial.modulus.get() as i64;
+    let mut dial = safe.dial.start.rem_euclid(modulus);
+    let mut count = int(0);
+    for &(d, val) in &safe.rotations {
+        // Clicks until the dial first reaches 0, then it passes 0 every `modulus` clicks
+        let first = if d == 1 { modulus - dial } else if dial == 0 { modulus } else { dial };
+        if val >= first {
+            count = num::add(&count, &int((val - first) / modulus + 1))?;
+        }
+        dial = (dial + d * (val % modulus)).rem_euclid(modulus);
+    }
+    return Ok(count);
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Secret Entrance";
+    const LABELS: [&'static str; 2] = [
+        "Password",
+        "Password",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../fixtures/example.txt"),
+            part1: Some("3"),
+            part2: Some("6"),
+        },
+    ];
+    const RECORDS: Option<Records> = Some(Records::Lines);
+
+    type Input = Safe;
+    type Part1 = i64;
+    type Part2 = Int;
+    type Params = Dial;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(contents, &Dial::default())
+    }
+
+    fn parse_with(contents: &str, dial: &Self::Params) -> Result<Self::Input, ParseError> {
+        safe(contents, dial)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(part2(input)?)
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day1>(1);
+    }
+
+    #[test]
+    fn p2_test() {
+        aoc2025::examples::check::<Day1>(2);
+    }
+
+    #[test]
+    fn huge_distances() {
+        let puzzle = aoc2025::Puzzle::of::<Day1>();
+        let solved = puzzle.solve("R9223372036854775807\nL9223372036854775807\n", &[1, 2]).unwrap();
+        let answers: Vec<String> = solved.parts.iter().map(|result| result.answer.to_string()).collect();
+        assert_eq!(answers, ["0", "184467440737095516"]);
+    }
+}
@@ -0,0 +1,39 @@
+use aoc2025::num::int;
+use aoc2025::Int;
+
+fn rotations(contents: &str) -> impl Iterator<Item = (i64, i64)> + '_ {
+    contents.lines().map(|line| {
+        let d = if line.starts_with('R') { 1 } else { -1 };
+        (d, line[1..].parse::<i64>().unwrap())
+    })
+}
+
+/// Turns the dial one click at a time, counting rotations that end on 0.
+pub fn part1(contents: &str) -> i64 {
+    let mut dial = 50;
+    let mut count = 0;
+    for (d, val) in rotations(contents) {
+        for _ in 0..val {
+            dial = (dial + d + 100) % 100;
+        }
+        if dial == 0 {
+            count += 1;
+        }
+    }
+    return count;
+}
+
+/// Turns the dial one click at a time, counting every click that lands on 0.
+pub fn part2(contents: &str) -> Int {
+    let mut dial = 50;
+    let mut count = 0;
+    for (d, val) in rotations(contents) {
+        for _ in 0..val {
+            dial = (dial + d + 100) % 100;
+            if dial == 0 {
+                count += 1;
+            }
+        }
+    }
+    return int(count);
+}
@@ -0,0 +1,75 @@
+use aoc2025::Rng;
+use std::collections::HashSet;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub machines: usize,
+    /// At least 2
+    pub max_lights: usize,
+    pub max_buttons: usize,
+    /// Most times any one button is pressed to reach the joltage requirements
+    pub max_presses: i64,
+}
+
+impl Params {
+    /// Small sizes also get fewer buttons and presses, so brute force can keep up.
+    pub fn sized(size: usize) -> Self {
+        Params {
+            machines: size.max(1),
+            max_lights: 10,
+            max_buttons: (size + 2).min(10),
+            max_presses: (size as i64).clamp(1, 20),
+        }
+    }
+}
+
+/// A `[light diagram] (button) ... {joltage requirements}` line.
+///
+/// The diagram and requirements are built from presses of the generated buttons,
+/// so both parts always have a solution.
+fn machine(rng: &mut Rng, params: &Params) -> String {
+    let lights = rng.int(2..=params.max_lights as i64) as usize;
+    let max_buttons = params.max_buttons.min((1 << lights) - 1);
+    let num_buttons = rng.int(1..=max_buttons as i64) as usize;
+
+    let mut seen: HashSet<u64> = HashSet::new();
+    let mut buttons: Vec<u64> = Vec::new();
+    while buttons.len() < num_buttons {
+        let button = rng.int(1..=(1 << lights) - 1) as u64;
+        if seen.insert(button) {
+            buttons.push(button);
+        }
+    }
+
+    let mut goal = buttons.iter().filter(|_| rng.chance(0.5)).fold(0, |acc, b| acc ^ b);
+    if goal == 0 {
+        goal = *rng.choose(&buttons);
+    }
+
+    let mut counts = vec![0; lights];
+    for button in &buttons {
+        let presses = rng.int(0..=params.max_presses);
+        for (light, count) in counts.iter_mut().enumerate() {
+            if button & (1 << light) != 0 {
+                *count += presses;
+            }
+        }
+    }
+
+    let diagram: String = (0..lights).map(|light| if goal & (1 << light) != 0 { '#' } else { '.' }).collect();
+    let buttons: Vec<String> = buttons.iter()
+        .map(|button| {
+            let toggled: Vec<String> = (0..lights).filter(|light| button & (1 << light) != 0).map(|light| light.to_string()).collect();
+            format!("({})", toggled.join(","))
+        })
+        .collect();
+    let counts: Vec<String> = counts.iter().map(i64::to_string).collect();
+
+    format!("[{}] {} {{{}}}\n", diagram, buttons.join(" "), counts.join(","))
+}
+
+/// One machine per line.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    (0..params.machines).map(|_| machine(rng, params)).collect()
+}
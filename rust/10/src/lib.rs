@@ -0,0 +1,256 @@
+use aoc2025::params::NoParams;
+use aoc2025::parse::{self, Line, Span};
+use aoc2025::memo::Memo;
+use aoc2025::num::{self, int};
+use aoc2025::{Example, Int, Overflow, ParseError, Records, Rng, SolveError, Solution};
+
+pub mod generate;
+pub mod reference;
+
+
+/// Fewest presses for one machine, or `None` if it can't be done, memoized by goal and
+/// remaining joltages.
+type PressMemo = Memo<(i64, Option<Vec<i64>>), Option<Int>>;
+
+fn min_presses(buttons: &[i64], goal: i64, remaining_counts: Option<Vec<i64>>, memo: &mut PressMemo) -> Result<Option<Int>, Overflow> {
+    if goal == 0 && remaining_counts.as_ref().is_none_or(|counts| counts.iter().all(|&count| count == 0)) {
+        return Ok(Some(int(0)));
+    }
+    let key = (goal, remaining_counts);
+    if let Some(minimum) = memo.get(&key) {
+        return Ok(minimum);
+    }
+    let (goal, remaining_counts) = &key;
+    let goal = *goal;
+    
+    let mut minimum: Option<Int> = None;
+    for choices in (0..2i64.pow(buttons.len() as u32)).map(|n| {
+        (0..buttons.len()).filter(|&i| (n & (1 << i)) != 0).collect::<Vec<usize>>()
+    }) {
+        let state = choices.iter().fold(0, |acc, &i| acc ^ buttons[i]);
+        if state != goal {
+            continue;
+        }
+        
+        let mut new_remaining: Vec<i64>;
+        if let Some(counts) = remaining_counts {
+            new_remaining = counts.to_vec();
+        } else {
+            minimum = fewer(minimum, int(choices.len() as i64));
+            continue;
+        }
+
+        for b in choices.iter() {
+            let mut v = buttons[*b];
+            let mut ix = 0;
+            while v != 0 {
+                new_remaining[ix] -= v & 1;
+                v >>= 1;
+                ix += 1;
+            }
+        }
+
+        if new_remaining.iter().any(|&count| count < 0) {
+            continue;
+        }
+
+        let mut new_goal = 0;
+        for ix in (0..new_remaining.len()).rev() {
+            new_remaining[ix] >>= 1;
+            new_goal = (new_goal << 1) | (new_remaining[ix] % 2);
+        }
+
+        if let Some(reduced_min) = min_presses(buttons, new_goal, Some(new_remaining), memo)? {
+            minimum = fewer(minimum, num::add(&num::mul(&int(2), &reduced_min)?, &int(choices.len() as i64))?);
+        }
+    }
+
+    return Ok(memo.insert(key, minimum));
+}
+
+fn fewer(minimum: Option<Int>, presses: Int) -> Option<Int> {
+    Some(match minimum {
+        Some(minimum) => minimum.min(presses),
+        None => presses,
+    })
+}
+
+
+/// The text between `open` and `close` around `part`.
+fn bracketed<'a>(line: Line<'a>, part: &'a str, open: char, close: char) -> Result<&'a str, ParseError> {
+    part.strip_prefix(open)
+        .and_then(|rest| rest.strip_suffix(close))
+        .ok_or_else(|| line.error(part, format!("'{}...{}'", open, close)))
+}
+
+/// Light diagram, buttons (as bitmasks of the lights they toggle) and joltage requirements.
+pub struct Machine {
+    goal: i64,
+    buttons: Vec<i64>,
+    joltages: Vec<i64>,
+    /// Where the light diagram and joltage requirements are, in case the buttons can't reach them.
+    diagram: Span,
+    requirements: Span,
+}
+
+fn machine(line: Line) -> Result<Machine, ParseError> {
+    let mut space_iter = line.text.split(' ');
+    let diagram = space_iter.next().unwrap();
+    let goal_str = bracketed(line, diagram, '[', ']')?;
+    line.check_chars(goal_str, |c| c == '.' || c == '#', "'.' or '#'")?;
+    // Lights are bits of an i64
+    if goal_str.len() > 63 {
+        return Err(line.error(goal_str, "at most 63 lights"));
+    }
+    let goal = goal_str.chars().rev().fold(0, |acc, c| acc << 1 | (c == '#') as i64);
+    let num_lights = goal_str.len();
+
+    let mut buttons: Vec<i64> = Vec::new();
+    let mut activate_counts: Option<(Vec<i64>, Span)> = None;
+    for s in space_iter {
+        if activate_counts.is_some() {
+            return Err(line.error(s, "nothing after the joltage requirements"));
+        }
+
+        if s.starts_with('(') {
+            // Sets of buttons are bits of an i64 too
+            if buttons.len() == 62 {
+                return Err(line.error(s, "at most 62 buttons"));
+            }
+            let mut button = 0;
+            for num_str in bracketed(line, s, '(', ')')?.split(',') {
+                let n = line.parse::<usize>(num_str, "a light index")?;
+                if n >= num_lights {
+                    return Err(line.error(num_str, format!("a light index below {}", num_lights)));
+                }
+                button |= 1 << n;
+            }
+            buttons.push(button);
+        } else {
+            let counts: Vec<i64> = bracketed(line, s, '{', '}')?.split(',')
+                .map(|num_str| match line.parse::<i64>(num_str, "a joltage requirement")? {
+                    count if count < 0 => Err(line.error(num_str, "a joltage requirement of at least 0")),
+                    count => Ok(count),
+                })
+                .collect::<Result<_, ParseError>>()?;
+            if counts.len() != num_lights {
+                return Err(line.error(s, format!("{} joltage requirements, one per light", num_lights)));
+            }
+            activate_counts = Some((counts, line.span(s)));
+        }
+    }
+
+    let (joltages, requirements) = activate_counts.ok_or_else(|| line.error_at_end("'{...}' joltage requirements"))?;
+    Ok(Machine { goal, buttons, joltages, diagram: line.span(diagram), requirements })
+}
+
+fn part1(machines: &[Machine]) -> Result<Int, SolveError> {
+    let presses = machines.iter()
+        .map(|machine| match min_presses(&machine.buttons, machine.goal, None, &mut Memo::new("min_presses"))? {
+            Some(p) => Ok(p),
+            None => Err(machine.diagram.error("a light diagram the buttons can reach").into()),
+        })
+        .collect::<Result<Vec<Int>, SolveError>>()?;
+    return Ok(num::sum(presses)?);
+}
+
+fn part2(machines: &[Machine]) -> Result<Int, SolveError> {
+    let presses = machines.iter()
+        .map(|machine| {
+            let joltages = &machine.joltages;
+            let goal = joltages.iter().rev().fold(0, |acc, x| acc << 1 | (x % 2));
+            match min_presses(&machine.buttons, goal, Some(joltages.clone()), &mut Memo::new("min_presses"))? {
+                Some(p) => Ok(p),
+                None => Err(machine.requirements.error("joltage requirements the buttons can reach").into()),
+            }
+        })
+        .collect::<Result<Vec<Int>, SolveError>>()?;
+    return Ok(num::sum(presses)?);
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Factory";
+    const LABELS: [&'static str; 2] = [
+        "Fewest # of presses",
+        "Fewest # of presses",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../fixtures/example.txt"),
+            part1: Some("7"),
+            part2: Some("33"),
+        },
+    ];
+    const RECORDS: Option<Records> = Some(Records::Lines);
+
+    type Input = Vec<Machine>;
+    type Part1 = Int;
+    type Part2 = Int;
+    type Params = NoParams;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        parse::lines(contents).map(machine).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        part2(input)
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day10>(1);
+    }
+
+    #[test]
+    fn p2_test() {
+        aoc2025::examples::check::<Day10>(2);
+    }
+
+    #[test]
+    fn too_many_buttons() {
+        let contents = format!("[.]{} {{0}}\n", " (0)".repeat(63));
+        let err = Day10::parse(&contents).err().unwrap();
+        assert_eq!((err.column, err.expected.as_str()), (3 + 62 * 4 + 2, "at most 62 buttons"));
+    }
+
+    #[test]
+    fn huge_joltages() {
+        let puzzle = aoc2025::Puzzle::of::<Day10>();
+        let solved = puzzle.solve("[.] (0) {9223372036854775807}\n", &[2]).unwrap();
+        assert_eq!(solved.parts[0].answer.to_string(), "9223372036854775807");
+
+        // 2^62 presses each, which only overflow once they're added up
+        let solved = puzzle.solve("[.] (0) {4611686018427387904}\n[.] (0) {4611686018427387904}\n", &[2]);
+        if aoc2025::num::INT_NAME == "i64" {
+            let err = solved.unwrap_err();
+            assert!(matches!(err, SolveError::Overflow(ref overflow) if overflow.day == 10 && overflow.part == 2), "{}", err);
+        } else {
+            assert_eq!(solved.unwrap().parts[0].answer.to_string(), "9223372036854775808");
+        }
+    }
+}
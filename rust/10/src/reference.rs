@@ -0,0 +1,73 @@
+use aoc2025::num::int;
+use aoc2025::Int;
+
+/// Light diagram, buttons (as the lights they toggle) and joltage requirements.
+type Machine = (Vec<bool>, Vec<Vec<usize>>, Vec<i64>);
+
+fn machines(contents: &str) -> Vec<Machine> {
+    contents.lines()
+        .map(|line| {
+            let parts: Vec<&str> = line.split(' ').collect();
+            let (diagram, rest) = parts.split_first().unwrap();
+            let (joltages, buttons) = rest.split_last().unwrap();
+
+            let lights = diagram.trim_matches(['[', ']']).chars().map(|c| c == '#').collect();
+            let buttons = buttons.iter()
+                .map(|b| b.trim_matches(['(', ')']).split(',').map(|n| n.parse().unwrap()).collect())
+                .collect();
+            let joltages = joltages.trim_matches(['{', '}']).split(',').map(|n| n.parse().unwrap()).collect();
+            (lights, buttons, joltages)
+        })
+        .collect()
+}
+
+/// Tries every set of buttons to press once.
+pub fn part1(contents: &str) -> Int {
+    machines(contents).iter()
+        .map(|(goal, buttons, _)| {
+            (0..1u32 << buttons.len())
+                .filter(|set| {
+                    let mut lights = vec![false; goal.len()];
+                    for (ix, button) in buttons.iter().enumerate() {
+                        if set & (1 << ix) != 0 {
+                            button.iter().for_each(|&light| lights[light] = !lights[light]);
+                        }
+                    }
+                    lights == *goal
+                })
+                .map(|set| int(set.count_ones() as i64))
+                .min()
+                .unwrap()
+        })
+        .sum()
+}
+
+/// Fewest presses of buttons `ix..` that bring `remaining` to exactly zero.
+fn fewest(buttons: &[Vec<usize>], ix: usize, remaining: &mut [i64]) -> Option<i64> {
+    if ix == buttons.len() {
+        return remaining.iter().all(|&r| r == 0).then_some(0);
+    }
+    // A counter no later button touches has to be done already
+    let touched_later = |light: usize| buttons[ix..].iter().any(|b| b.contains(&light));
+    if (0..remaining.len()).any(|light| remaining[light] != 0 && !touched_later(light)) {
+        return None;
+    }
+
+    let most = buttons[ix].iter().map(|&light| remaining[light]).min().unwrap();
+    let mut best: Option<i64> = None;
+    for presses in 0..=most {
+        buttons[ix].iter().for_each(|&light| remaining[light] -= presses);
+        if let Some(rest) = fewest(buttons, ix + 1, remaining) {
+            best = Some(best.map_or(presses + rest, |b| b.min(presses + rest)));
+        }
+        buttons[ix].iter().for_each(|&light| remaining[light] += presses);
+    }
+    return best;
+}
+
+/// Tries every number of presses of every button.
+pub fn part2(contents: &str) -> Int {
+    machines(contents).iter()
+        .map(|(_, buttons, joltages)| int(fewest(buttons, 0, &mut joltages.clone()).unwrap()))
+        .sum()
+}
@@ -0,0 +1,81 @@
+use aoc2025::Rng;
+use std::collections::HashSet;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// Bottleneck devices between `svr` and `out`, two of which are `fft` and `dac`
+    pub stages: usize,
+    /// Devices in each layer between bottlenecks, at least 6
+    pub width: usize,
+    /// Layers between bottlenecks
+    pub depth: usize,
+}
+
+impl Params {
+    /// Each stage multiplies the number of paths by up to `width * 2^(depth - 1)`, so the
+    /// stage count is capped to keep part 2 within `i64`.
+    pub fn sized(size: usize) -> Self {
+        Params { stages: (size / 20).clamp(2, 10), width: 6, depth: 3 }
+    }
+}
+
+const RESERVED: [&str; 5] = ["you", "out", "svr", "fft", "dac"];
+
+fn name(rng: &mut Rng, used: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = (0..3).map(|_| (b'a' + rng.below(26) as u8) as char).collect();
+        if !RESERVED.contains(&name.as_str()) && used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// A layered device graph shaped like the puzzle's: `svr`, then a cloud of devices
+/// narrowing to a single bottleneck device, repeated for each stage, then a last cloud
+/// feeding `out`. `fft` and `dac` are two of the bottlenecks and `you` is in the last cloud.
+///
+/// Inside a cloud every device has at most two inputs, while bottlenecks and `out` have
+/// `width`, so part 2's "most connections" devices are exactly the bottlenecks.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    assert!(params.stages >= 2, "Need at least 2 stages for 'fft' and 'dac'");
+    assert!(params.width >= 6 && params.depth >= 1, "Clouds too small to tell apart from bottlenecks");
+
+    let mut used: HashSet<String> = HashSet::new();
+    let mut bottlenecks: Vec<String> = (0..params.stages).map(|_| name(rng, &mut used)).collect();
+    let fft = rng.index(params.stages);
+    let dac = (fft + 1 + rng.index(params.stages - 1)) % params.stages;
+    bottlenecks[fft] = "fft".to_string();
+    bottlenecks[dac] = "dac".to_string();
+    bottlenecks.push("out".to_string());
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut from = "svr".to_string();
+    for (stage, to) in bottlenecks.iter().enumerate() {
+        let mut layers: Vec<Vec<String>> = (0..params.depth)
+            .map(|_| (0..params.width).map(|_| name(rng, &mut used)).collect())
+            .collect();
+        if stage == params.stages {
+            layers[0][0] = "you".to_string();
+        }
+
+        lines.push(format!("{}: {}", from, layers[0].join(" ")));
+        for pair in layers.windows(2) {
+            for (ix, device) in pair[0].iter().enumerate() {
+                let mut outputs = vec![pair[1][ix].as_str()];
+                if rng.chance(0.5) {
+                    outputs.push(&pair[1][(ix + 1) % params.width]);
+                }
+                lines.push(format!("{}: {}", device, outputs.join(" ")));
+            }
+        }
+        for device in layers.last().unwrap() {
+            lines.push(format!("{}: {}", device, to));
+        }
+
+        from = to.clone();
+    }
+
+    rng.shuffle(&mut lines);
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
@@ -0,0 +1,279 @@
+use aoc2025::parse::{self, Line, Span};
+use aoc2025::memo::Memo;
+use aoc2025::num::{self, int};
+use aoc2025::{Example, Int, Overflow, ParseError, Records, Rng, SolveError, Solution};
+use std::collections::*;
+use serde::Deserialize;
+
+pub mod generate;
+pub mod reference;
+
+
+/// Paths between two devices, memoized by start, end and the devices to avoid.
+type PathMemo = Memo<(String, String, Vec<bool>), Int>;
+
+fn num_paths(keys: &[String], connections: &[Vec<String>], start: String, end: String, visited: Vec<bool>, memo: &mut PathMemo) -> Result<Int, Overflow> {
+    if start == end {
+        return Ok(int(1));
+    }
+    let key = (start, end, visited);
+    if let Some(paths) = memo.get(&key) {
+        return Ok(paths);
+    }
+    let (start, end, visited) = &key;
+
+    let mut total_paths = int(0);
+    let start_index = keys.iter().position(|k| k == start).unwrap();
+    for neighbor in &connections[start_index] {
+        let neighbor_index = keys.iter().position(|k| *k == *neighbor).unwrap();
+        if !visited[neighbor_index] {
+            let paths = num_paths(keys, connections, neighbor.to_string(), end.clone(), (0..visited.len()).map(|ix| if ix == neighbor_index { true } else { visited[ix] }).collect::<Vec<bool>>(), memo)?;
+            total_paths = num::add(&total_paths, &paths)?;
+        }
+    }
+
+    return Ok(memo.insert(key, total_paths));
+}
+
+
+fn device(line: Line) -> Result<(String, Vec<String>), ParseError> {
+    let (from_key, to_keys) = line.split_once(line.text, ':')?;
+    if from_key.is_empty() {
+        return Err(line.error(from_key, "a device name"));
+    }
+
+    Ok((from_key.to_string(), to_keys.split_whitespace().map(|s| s.to_string()).collect()))
+}
+
+/// Which devices the paths run between.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Devices {
+    /// Where part 1's paths start.
+    pub start: String,
+    /// Where part 2's paths start.
+    pub server: String,
+    pub end: String,
+    /// Devices part 2's paths must all pass through.
+    pub required: Vec<String>,
+}
+
+impl Default for Devices {
+    fn default() -> Self {
+        Devices {
+            start: "you".to_string(),
+            server: "svr".to_string(),
+            end: "out".to_string(),
+            required: vec!["dac".to_string(), "fft".to_string()],
+        }
+    }
+}
+
+/// The devices' outputs, both by name and indexed by position in the sorted device names.
+pub struct Reactor {
+    devices: Devices,
+    connections: HashMap<String, Vec<String>>,
+    in_connections: HashMap<String, i64>,
+    sorted_keys: Vec<String>,
+    connection_vecs: Vec<Vec<String>>,
+    /// Where to point when a device is missing.
+    end: Span,
+}
+
+fn reactor(contents: &str, devices: &Devices) -> Result<Reactor, ParseError> {
+    let mut in_connections: HashMap<String, i64> = HashMap::new();
+    let connections: HashMap<String, Vec<String>> = parse::lines(contents)
+        .map(|line| {
+            let (from_key, to_keys) = device(line)?;
+            for to_key in &to_keys {
+                *in_connections.entry(to_key.to_string()).or_insert(0) += 1;
+            }
+
+            Ok((from_key, to_keys))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let mut sorted_keys: Vec<String> = HashSet::<String>::from_iter(connections.keys()
+        .flat_map(|k| {
+            vec![k.to_string()].into_iter().chain(connections.get(k).unwrap().to_vec())
+        })).into_iter().collect();
+
+    sorted_keys.sort();
+    let connection_vecs: Vec<Vec<String>> = sorted_keys.iter().map(|k| connections.get(k).unwrap_or(&vec![]).to_vec()).collect();
+
+    Ok(Reactor { devices: devices.clone(), connections, in_connections, sorted_keys, connection_vecs, end: Span::end_of_input(contents) })
+}
+
+fn part1(reactor: &Reactor) -> Result<Int, SolveError> {
+    let Devices { start, end, .. } = &reactor.devices;
+    if !reactor.sorted_keys.contains(start) {
+        return Err(reactor.end.error(format!("a device named '{}'", start)).into());
+    }
+
+    return Ok(num_paths(
+        &reactor.sorted_keys,
+        &reactor.connection_vecs,
+        start.clone(),
+        end.clone(),
+        vec![false; reactor.sorted_keys.len()],
+        &mut Memo::new("num_paths"),
+    )?);
+}
+
+fn part2(reactor: &Reactor) -> Result<Int, Overflow> {
+    let Reactor { devices, connections, in_connections, sorted_keys, connection_vecs, .. } = reactor;
+    let Devices { server, end, required, .. } = devices;
+
+    let mut most_connections: Vec<String> = sorted_keys.clone();
+    most_connections.sort_by_key(|k| -in_connections.get(k).unwrap_or(&0));
+    most_connections = most_connections.clone().into_iter().filter(|k| *in_connections.get(k).unwrap_or(&0) >= (in_connections.get(most_connections.clone().first().unwrap()).unwrap_or(&0)) / 2).collect::<Vec<String>>();
+    for k in required.iter().chain([end]) {
+        if !most_connections.contains(k) {
+            most_connections.push(k.to_string());
+        }
+    }
+    
+    let mut poi_paths: HashMap<String, HashSet<String>> = HashMap::new();
+    let mut positions: VecDeque<(String, String)> = VecDeque::from(connections.get(server).unwrap_or(&vec![]).iter().map(|k| (k.to_string(), server.to_string())).collect::<Vec<(String, String)>>());
+    let mut visited: HashSet<(String, String)> = HashSet::new();
+
+    while let Some((current, from)) = positions.pop_front() {
+        let new_from =if most_connections.contains(&current) {
+            poi_paths.entry(from.clone()).or_default().insert(current.clone());
+            current.clone()
+        } else {
+            from.clone()
+        };
+
+        for neighbor in connections.get(&current).unwrap_or(&vec![]) {
+            if visited.contains(&(neighbor.to_string(), new_from.clone())) {
+                continue;
+            }
+            visited.insert((neighbor.to_string(), new_from.clone()));
+            positions.push_back((neighbor.to_string(), new_from.clone()));
+        }
+    }
+
+    for k in most_connections.clone() {
+        let required_intersection: HashSet<String> = poi_paths.get(&k).unwrap_or(&HashSet::new()).intersection(&HashSet::from_iter(required.iter().cloned())).cloned().collect();
+        if !required_intersection.is_empty() {
+            poi_paths.insert(k.clone(), required_intersection);
+        }
+    }
+
+    let mut main_paths: HashSet<Vec<String>> = HashSet::new();
+    let mut path_positions: VecDeque<Vec<String>> = VecDeque::from([vec![server.to_string()]]);
+    
+    while let Some(current_path) = path_positions.pop_front() {
+        let last_node = current_path.last().unwrap().to_string();
+        if last_node == *end {
+            if required.iter().all(|k| current_path.contains(k)) {
+                main_paths.insert(current_path.clone());
+            }
+            continue;
+        }
+
+        for neighbor in poi_paths.get(&last_node).unwrap_or(&HashSet::new()) {
+            if current_path.contains(neighbor) {
+                continue;
+            }
+            let mut new_path = current_path.clone();
+            new_path.push(neighbor.to_string());
+            path_positions.push_back(new_path);
+        }
+    }
+
+    let mut memo = Memo::new("num_paths");
+    return num::try_sum(main_paths.iter().map(|p| {
+        num::try_product(p.windows(2).map(|w| {
+            let start = w[0].to_string();
+            let end = w[1].to_string();
+            let visited = sorted_keys.iter().map(|sk| *sk != end && most_connections.contains(sk)).collect::<Vec<bool>>();
+            num_paths(sorted_keys, connection_vecs, start, end, visited, &mut memo)
+        }))
+    }));
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Reactor";
+    const LABELS: [&'static str; 2] = [
+        "# of paths from the start device to the end",
+        "# of paths from the server to the end through the required devices",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example1",
+            input: include_str!("../fixtures/example1.txt"),
+            part1: Some("5"),
+            part2: None,
+        },
+        Example {
+            name: "example2",
+            input: include_str!("../fixtures/example2.txt"),
+            part1: None,
+            part2: Some("2"),
+        },
+    ];
+    const RECORDS: Option<Records> = Some(Records::Edges);
+
+    type Input = Reactor;
+    type Part1 = Int;
+    type Part2 = Int;
+    type Params = Devices;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        reactor(contents, &Devices::default())
+    }
+
+    fn parse_with(contents: &str, devices: &Self::Params) -> Result<Self::Input, ParseError> {
+        reactor(contents, devices)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(part2(input)?)
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day11>(1);
+    }
+
+    #[test]
+    fn p2_test() {
+        aoc2025::examples::check::<Day11>(2);
+    }
+
+    #[test]
+    fn params_test() {
+        let params = ["start=bbb", "required=[\"fft\"]"].iter().map(|pair| aoc2025::params::parse_override(pair).unwrap()).collect();
+        let puzzle = aoc2025::Puzzle::of::<Day11>();
+        let solved = puzzle.solve_with(Day11::EXAMPLES[0].input, &[1], &params).unwrap();
+        assert_eq!(solved.parts[0].answer.to_string(), "2");
+        let solved = puzzle.solve_with(Day11::EXAMPLES[1].input, &[2], &params).unwrap();
+        assert_eq!(solved.parts[0].answer.to_string(), "4");
+    }
+}
@@ -0,0 +1,37 @@
+use aoc2025::num::int;
+use aoc2025::Int;
+use std::collections::HashMap;
+
+fn devices(contents: &str) -> HashMap<&str, Vec<&str>> {
+    contents.lines()
+        .map(|line| {
+            let (from, to) = line.split_once(": ").unwrap();
+            (from, to.split(' ').collect())
+        })
+        .collect()
+}
+
+/// Paths from `device` to `out`, and of those, how many pass `fft` and `dac` (given
+/// whether they've been passed already), walking every path separately.
+fn paths(devices: &HashMap<&str, Vec<&str>>, device: &str, fft: bool, dac: bool) -> (Int, Int) {
+    if device == "out" {
+        return (int(1), int((fft && dac) as i64));
+    }
+
+    let mut total = (int(0), int(0));
+    for next in devices.get(device).map_or(&[][..], Vec::as_slice) {
+        let (all, through) = paths(devices, next, fft || *next == "fft", dac || *next == "dac");
+        total = (total.0 + all, total.1 + through);
+    }
+    return total;
+}
+
+/// Walks every path from `you`.
+pub fn part1(contents: &str) -> Int {
+    paths(&devices(contents), "you", false, false).0
+}
+
+/// Walks every path from `svr`.
+pub fn part2(contents: &str) -> Int {
+    paths(&devices(contents), "svr", false, false).1
+}
@@ -0,0 +1,70 @@
+use aoc2025::Rng;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub regions: usize,
+    pub min_side: i64,
+    pub max_side: i64,
+}
+
+impl Params {
+    /// Small sizes also get small regions, so brute force can keep up.
+    pub fn sized(size: usize) -> Self {
+        Params { regions: size.max(1), min_side: 6, max_side: (6 + size as i64).min(50) }
+    }
+}
+
+const SHAPES: usize = 6;
+
+/// A 3x3 present with 5 to 7 cells, touching every edge of its box.
+fn shape(rng: &mut Rng) -> Vec<Vec<bool>> {
+    loop {
+        let shape: Vec<Vec<bool>> = (0..3).map(|_| (0..3).map(|_| rng.chance(0.7)).collect()).collect();
+        let cells = shape.iter().flatten().filter(|&&c| c).count();
+        let rows_filled = shape.iter().all(|row| row.iter().any(|&c| c));
+        let cols_filled = (0..3).all(|c| shape.iter().any(|row| row[c]));
+        if (5..=7).contains(&cells) && rows_filled && cols_filled {
+            return shape;
+        }
+    }
+}
+
+/// Six 3x3 presents, then one region per line. Like the puzzle's input, each region either
+/// has room for every present in its own 3x3 block or has fewer cells than the presents need,
+/// so it never comes down to actually packing them.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let shapes: Vec<Vec<Vec<bool>>> = (0..SHAPES).map(|_| shape(rng)).collect();
+    let sizes: Vec<i64> = shapes.iter().map(|shape| shape.iter().flatten().filter(|&&c| c).count() as i64).collect();
+
+    let mut contents = String::new();
+    for (ix, shape) in shapes.iter().enumerate() {
+        contents += &format!("{}:\n", ix);
+        for row in shape {
+            contents += &row.iter().map(|&c| if c { '#' } else { '.' }).collect::<String>();
+            contents += "\n";
+        }
+        contents += "\n";
+    }
+
+    for _ in 0..params.regions {
+        let (w, h) = (rng.int(params.min_side..=params.max_side), rng.int(params.min_side..=params.max_side));
+        let mut counts = [0i64; SHAPES];
+        if rng.chance(0.5) {
+            for _ in 0..rng.int(1..=(w / 3) * (h / 3)) {
+                counts[rng.index(SHAPES)] += 1;
+            }
+        } else {
+            let mut filled = 0;
+            while filled <= w * h {
+                let kind = rng.index(SHAPES);
+                counts[kind] += 1;
+                filled += sizes[kind];
+            }
+        }
+
+        let counts: Vec<String> = counts.iter().map(i64::to_string).collect();
+        contents += &format!("{}x{}: {}\n", w, h, counts.join(" "));
+    }
+    contents
+}
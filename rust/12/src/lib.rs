@@ -0,0 +1,361 @@
+use aoc2025::params::NoParams;
+use aoc2025::parse::{self, Line, Span};
+use aoc2025::num::{self, int};
+use aoc2025::{Example, ParseError, Records, Rng, SolveError, Solution};
+use std::collections::*;
+
+pub mod generate;
+pub mod reference;
+
+fn shapes(contents: &str) -> Result<Vec<Vec<Vec<bool>>>, ParseError> {
+    contents.split("\n\n")
+        .filter(|shape_str| shape_str.lines().next().is_some_and(|line| line.ends_with(':')))
+        .map(|shape_str| {
+            let header = parse::section_lines(contents, shape_str).next().unwrap();
+            let width = shape_str.lines().nth(1).map_or(0, str::len);
+            let shape: Vec<Vec<bool>> = parse::section_lines(contents, shape_str)
+                .skip(1)
+                .map(|line| {
+                    line.check_chars(line.text, |c| c == '#' || c == '.', "'#' or '.'")?;
+                    if line.text.len() != width {
+                        return Err(line.error(line.text, format!("a row {} cells wide", width)));
+                    }
+                    Ok(line.text.chars().map(|c| c == '#').collect::<Vec<bool>>())
+                })
+                .collect::<Result<_, ParseError>>()?;
+
+            if !shape.iter().flatten().any(|&c| c) {
+                return Err(header.error_at_end("a shape with at least one '#' below its index"));
+            }
+            Ok(shape)
+        })
+        .collect()
+}
+
+/// Region dimensions and how many of each of the `shape_count` shapes must fit in it.
+fn region(line: Line, shape_count: usize) -> Result<(Vec<i64>, Vec<i64>, Span), ParseError> {
+    let (dims_str, counts_str) = line.split_once(line.text, ':')?;
+    let (w_str, h_str) = line.split_once(dims_str, 'x')?;
+    let non_negative = |part: &str, expected: &str| match line.parse::<i64>(part, expected)? {
+        n if n < 0 => Err(line.error(part, format!("{} of at least 0", expected))),
+        n => Ok(n),
+    };
+    let shape = vec![
+        non_negative(w_str, "a region width")?,
+        non_negative(h_str, "a region height")?,
+    ];
+    let counts: Vec<i64> = counts_str.split_whitespace()
+        .map(|n| non_negative(n, "a present count"))
+        .collect::<Result<_, ParseError>>()?;
+    if counts.len() != shape_count {
+        return Err(line.error(counts_str, format!("{} present counts, one per shape", shape_count)));
+    }
+
+    Ok((shape, counts, line.span(dims_str)))
+}
+
+/// Distinct rotations and reflections of `shape`, each as cell offsets from its first cell in reading order.
+fn orientations(shape: &[Vec<bool>]) -> Vec<Vec<(i64, i64)>> {
+    let cells: Vec<(i64, i64)> = shape.iter().enumerate()
+        .flat_map(|(r, row)| row.iter().enumerate().filter(|(_, &c)| c).map(move |(c, _)| (r as i64, c as i64)))
+        .collect();
+
+    let mut orientations: Vec<Vec<(i64, i64)>> = Vec::new();
+    for flip in [false, true] {
+        for turns in 0..4 {
+            let mut oriented: Vec<(i64, i64)> = cells.iter().map(|&(r, c)| {
+                let (mut r, mut c) = if flip { (r, -c) } else { (r, c) };
+                for _ in 0..turns {
+                    (r, c) = (c, -r);
+                }
+                (r, c)
+            }).collect();
+            oriented.sort();
+
+            let (r0, c0) = oriented[0];
+            let oriented: Vec<(i64, i64)> = oriented.iter().map(|&(r, c)| (r - r0, c - c0)).collect();
+            if !orientations.contains(&oriented) {
+                orientations.push(oriented);
+            }
+        }
+    }
+    return orientations;
+}
+
+/// Largest region `fill` will try to pack, since it recurses once per cell.
+const MAX_SEARCH_AREA: i64 = 64 * 64;
+
+/// Search states known not to fit (next cell, the cells from there on and counts left),
+/// with the most slack they've failed with. Anything with less slack fails too.
+type Dead = HashMap<(usize, Vec<bool>, Vec<i64>), i64>;
+
+/// Fills the `w` wide `grid` from cell `start` onwards in reading order, either covering each
+/// empty cell with a present's first cell or leaving it empty while `slack` allows.
+fn fill(grid: &mut [bool], w: usize, start: usize, pieces: &[Vec<Vec<(i64, i64)>>], counts: &mut [i64], slack: i64, dead: &mut Dead) -> bool {
+    if counts.iter().all(|&count| count == 0) {
+        return true;
+    }
+
+    let Some(ix) = (start..grid.len()).find(|&ix| !grid[ix]) else {
+        return false;
+    };
+    let (h, r, c) = ((grid.len() / w) as i64, (ix / w) as i64, (ix % w) as i64);
+
+    // Everything before `ix` is settled, so the rest of the grid is all that matters
+    let state = (ix, grid[ix..].to_vec(), counts.to_vec());
+    if dead.get(&state).is_some_and(|&failed| slack <= failed) {
+        return false;
+    }
+
+    for (kind, orientations) in pieces.iter().enumerate() {
+        if counts[kind] == 0 {
+            continue;
+        }
+
+        for cells in orientations {
+            let fits_here = cells.iter().all(|&(dr, dc)| {
+                let (r, c) = (r + dr, c + dc);
+                r < h && c >= 0 && c < w as i64 && !grid[(r * w as i64 + c) as usize]
+            });
+            if !fits_here {
+                continue;
+            }
+
+            let placed = || cells.iter().map(|&(dr, dc)| ((r + dr) * w as i64 + c + dc) as usize);
+            placed().for_each(|ix| grid[ix] = true);
+            counts[kind] -= 1;
+            let fits = fill(grid, w, ix + 1, pieces, counts, slack, dead);
+            counts[kind] += 1;
+            placed().for_each(|ix| grid[ix] = false);
+
+            if fits {
+                return true;
+            }
+        }
+    }
+
+    if slack > 0 {
+        grid[ix] = true;
+        let fits = fill(grid, w, ix + 1, pieces, counts, slack - 1, dead);
+        grid[ix] = false;
+        if fits {
+            return true;
+        }
+    }
+
+    dead.insert(state, slack);
+    return false;
+}
+
+/// The present shapes, each shape's orientations, and the regions to fit them into.
+pub struct Farm {
+    shapes: Vec<Vec<Vec<bool>>>,
+    pieces: Vec<Vec<Vec<(i64, i64)>>>,
+    regions: Vec<(Vec<i64>, Vec<i64>, Span)>,
+    /// Height and width shared by every shape, if there are any.
+    shape_dims: Option<(i64, i64)>,
+}
+
+fn farm(contents: &str) -> Result<Farm, ParseError> {
+    let shapes: Vec<Vec<Vec<bool>>> = shapes(contents)?;
+    let regions: Vec<(Vec<i64>, Vec<i64>, Span)> = parse::section_lines(contents, contents.split("\n\n").last().unwrap())
+        .map(|line| region(line, shapes.len()))
+        .collect::<Result<_, ParseError>>()?;
+    let pieces: Vec<Vec<Vec<(i64, i64)>>> = shapes.iter().map(|shape| orientations(shape)).collect();
+
+    let shape_dims: HashSet<(i64, i64)> = HashSet::from_iter(shapes.iter().map(|shape| {
+        (shape.len() as i64, shape[0].len() as i64)
+    }));
+    if shape_dims.len() > 1 {
+        return Err(ParseError::end_of_input(contents, "shapes that are all the same size"));
+    }
+    let shape_dims = shape_dims.into_iter().next();
+
+    Ok(Farm { shapes, pieces, regions, shape_dims })
+}
+
+/// Every shape that isn't the size of the first, which `farm` only reports once.
+fn lint(contents: &str) -> Vec<ParseError> {
+    let mut first: Option<(usize, usize, usize)> = None;
+    let mut problems: Vec<ParseError> = Vec::new();
+    for shape_str in contents.split("\n\n").filter(|shape_str| shape_str.lines().next().is_some_and(|line| line.ends_with(':'))) {
+        let header = parse::section_lines(contents, shape_str).next().unwrap();
+        let dims = (shape_str.lines().count() - 1, shape_str.lines().nth(1).map_or(0, str::len));
+        match first {
+            Some((line, h, w)) if (h, w) != dims => {
+                problems.push(header.error(header.text, format!("a {}x{} shape like the one on line {}", w, h, line)));
+            }
+            Some(_) => {}
+            None => first = Some((header.number, dims.0, dims.1)),
+        }
+    }
+    problems
+}
+
+/// How many cells of its region `shape` covers.
+fn cells(shape: &[Vec<bool>]) -> i64 {
+    shape.iter().flatten().filter(|&&c| c).count() as i64
+}
+
+fn part1(farm: &Farm) -> Result<i64, SolveError> {
+    let Farm { shapes, pieces, regions, shape_dims } = farm;
+
+    let mut count = 0;
+    for (shape, counts, span) in regions {
+        // Sizes and counts are unbounded, so the bounds are checked like answers are
+        let filled = num::try_sum(counts.iter().zip(shapes.iter()).map(|(&count, shape)| num::mul(&int(count), &int(cells(shape)))))?;
+        let area = num::mul(&int(shape[0]), &int(shape[1]))?;
+        if filled > area {
+            continue;
+        }
+
+        // Without any shapes there's nothing to fit
+        let Some((shape_h, shape_w)) = *shape_dims else {
+            count += 1;
+            continue;
+        };
+        let blocks = num::mul(&int(shape[0] / shape_h), &int(shape[1] / shape_w))?;
+        if num::sum(counts.iter().map(|&count| int(count)))? <= blocks {
+            count += 1;
+            continue;
+        }
+
+        // Neither bound settles it, so actually try to pack the presents
+        if area > int(MAX_SEARCH_AREA) {
+            return Err(span.unsupported(format!("regions over {} cells are too big to search for a packing", MAX_SEARCH_AREA)).into());
+        }
+        // Everything is at most MAX_SEARCH_AREA from here, so plain i64 is safe
+        let area = shape[0] * shape[1];
+        let filled: i64 = counts.iter().zip(shapes.iter()).map(|(count, shape)| count * cells(shape)).sum();
+        let mut grid = vec![false; area as usize];
+        let mut counts = counts.clone();
+        if fill(&mut grid, shape[0] as usize, 0, pieces, &mut counts, area - filled, &mut HashMap::new()) {
+            count += 1;
+        }
+    }
+    return Ok(count);
+}
+
+fn part2(_farm: &Farm) -> String {
+    return "Christmas has been saved!".to_string();
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Christmas Tree Farm";
+    const LABELS: [&'static str; 2] = [
+        "Regions that can fit the listed presents",
+        "",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../fixtures/example.txt"),
+            part1: Some("2"),
+            part2: None,
+        },
+    ];
+    const RECORDS: Option<Records> = Some(Records::LastSection);
+
+    type Input = Farm;
+    type Part1 = i64;
+    type Part2 = String;
+    type Params = NoParams;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        farm(contents)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(part2(input))
+    }
+
+    fn lint(contents: &str) -> Vec<ParseError> {
+        lint(contents)
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day12>(1);
+    }
+
+    /// Whether each region of `contents` can be packed, searching even where a bound settles it.
+    fn packings(contents: &str) -> Vec<bool> {
+        let farm = farm(contents).unwrap();
+        farm.regions.iter().map(|(shape, counts, _)| {
+            let filled: i64 = counts.iter().zip(&farm.shapes).map(|(count, shape)| count * shape.iter().flatten().filter(|&&c| c).count() as i64).sum();
+            let area = shape[0] * shape[1];
+            filled <= area && fill(&mut vec![false; area as usize], shape[0] as usize, 0, &farm.pieces, &mut counts.clone(), area - filled, &mut HashMap::new())
+        }).collect()
+    }
+
+    #[test]
+    fn orientations_test() {
+        let count = |shape: &[&str]| orientations(&shape.iter().map(|row| row.chars().map(|c| c == '#').collect()).collect::<Vec<_>>()).len();
+        assert_eq!(count(&["##", "##"]), 1);
+        assert_eq!(count(&["###", ".#.", "###"]), 2);
+        assert_eq!(count(&["##", "#."]), 4);
+        assert_eq!(count(&["###", "##.", "##."]), 8);
+    }
+
+    #[test]
+    fn fill_test() {
+        // Two L trominoes only fit a 3x2 region by interlocking, and three never tile a 3x3 one
+        assert_eq!(packings("0:\n##\n#.\n\n3x2: 2\n2x3: 2\n3x3: 3\n"), vec![true, true, false]);
+        // Two 2x2 squares have enough cells in a 3x3 region but can't both fit
+        assert_eq!(packings("0:\n##\n##\n\n3x3: 2\n4x2: 2\n"), vec![false, true]);
+        assert_eq!(packings(Day12::EXAMPLES[0].input), vec![true, true, false]);
+    }
+
+    #[test]
+    fn search_is_bounded() {
+        // Enough cells for the presents but not a block each, so only a search could tell
+        let solved = aoc2025::Puzzle::of::<Day12>().solve("0:\n##\n#.\n\n100x100: 0\n1000x1000: 300000\n", &[1]);
+        let Err(SolveError::Unsupported(err)) = solved else { panic!("expected an unsupported region") };
+        assert_eq!((err.day, err.part, err.span.line, err.span.text.as_str()), (12, 1, 6, "1000x1000"));
+    }
+
+    #[test]
+    fn overflow_is_reported() {
+        let contents = "0:\n#\n\n9999999999x9999999999: 1\n";
+        let solved = aoc2025::Puzzle::of::<Day12>().solve(contents, &[1]);
+
+        if aoc2025::num::INT_NAME == "i64" {
+            let err = solved.unwrap_err();
+            assert!(matches!(err, SolveError::Overflow(ref overflow) if overflow.day == 12 && overflow.part == 1), "{}", err);
+        } else {
+            assert_eq!(solved.unwrap().parts[0].answer.to_string(), "1");
+        }
+    }
+
+    #[test]
+    fn lint_test() {
+        let problems = Day12::lint("0:\n##\n#.\n\n1:\n###\n\n2:\n.#\n##\n\n4x4: 1 1 1\n");
+        let found: Vec<(usize, &str)> = problems.iter().map(|err| (err.line, err.expected.as_str())).collect();
+        assert_eq!(found, vec![(5, "a 2x2 shape like the one on line 1")]);
+        assert!(Day12::lint(Day12::EXAMPLES[0].input).is_empty());
+    }
+}
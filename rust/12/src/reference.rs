@@ -0,0 +1,91 @@
+/// A region's width, height and present counts.
+type Region = (usize, usize, Vec<usize>);
+
+/// The presents' cells, and the regions.
+fn puzzle(contents: &str) -> (Vec<Vec<(usize, usize)>>, Vec<Region>) {
+    let blocks: Vec<&str> = contents.split("\n\n").collect();
+    let (regions, shapes) = blocks.split_last().unwrap();
+
+    let shapes = shapes.iter()
+        .map(|shape| {
+            shape.lines().skip(1).enumerate()
+                .flat_map(|(r, row)| row.chars().enumerate().filter(|&(_, c)| c == '#').map(move |(c, _)| (r, c)))
+                .collect()
+        })
+        .collect();
+    let regions = regions.lines()
+        .map(|line| {
+            let (dims, counts) = line.split_once(": ").unwrap();
+            let (w, h) = dims.split_once('x').unwrap();
+            (w.parse().unwrap(), h.parse().unwrap(), counts.split(' ').map(|n| n.parse().unwrap()).collect())
+        })
+        .collect();
+    (shapes, regions)
+}
+
+/// All 8 rotations and reflections of a 3x3 present, duplicates included.
+fn orientations(cells: &[(usize, usize)]) -> Vec<Vec<(usize, usize)>> {
+    let mut orientations = Vec::new();
+    let mut cells = cells.to_vec();
+    for _ in 0..4 {
+        cells = cells.iter().map(|&(r, c)| (c, 2 - r)).collect();
+        orientations.push(cells.clone());
+        orientations.push(cells.iter().map(|&(r, c)| (r, 2 - c)).collect());
+    }
+    return orientations;
+}
+
+/// Places `presents` one at a time at every position and orientation. Identical presents
+/// are placed in increasing positions, so they aren't tried in every order.
+fn place(grid: &mut Vec<Vec<bool>>, presents: &[Vec<Vec<(usize, usize)>>], min_pos: usize) -> bool {
+    let Some((present, rest)) = presents.split_first() else {
+        return true;
+    };
+    let (h, w) = (grid.len(), grid[0].len());
+
+    for pos in min_pos..h.saturating_sub(2) * w {
+        let (r, c) = (pos / w, pos % w);
+        if c + 2 >= w {
+            continue;
+        }
+
+        for cells in present {
+            if cells.iter().any(|&(dr, dc)| grid[r + dr][c + dc]) {
+                continue;
+            }
+
+            cells.iter().for_each(|&(dr, dc)| grid[r + dr][c + dc] = true);
+            let next_min = if rest.first() == Some(present) { pos } else { 0 };
+            let fits = place(grid, rest, next_min);
+            cells.iter().for_each(|&(dr, dc)| grid[r + dr][c + dc] = false);
+
+            if fits {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+/// Searches for a packing of every region that has enough cells.
+pub fn part1(contents: &str) -> i64 {
+    let (shapes, regions) = puzzle(contents);
+
+    regions.into_iter()
+        .filter(|(w, h, counts)| {
+            let cells: usize = counts.iter().zip(&shapes).map(|(count, shape)| count * shape.len()).sum();
+            if cells > w * h {
+                return false;
+            }
+
+            let presents: Vec<Vec<Vec<(usize, usize)>>> = counts.iter().zip(&shapes)
+                .flat_map(|(&count, shape)| std::iter::repeat_n(orientations(shape), count))
+                .collect();
+            place(&mut vec![vec![false; *w]; *h], &presents, 0)
+        })
+        .count() as i64
+}
+
+pub fn part2(_contents: &str) -> String {
+    "Christmas has been saved!".to_string()
+}
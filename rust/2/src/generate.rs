@@ -0,0 +1,36 @@
+use aoc2025::Rng;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub ranges: usize,
+    /// IDs have at most this many digits
+    pub max_digits: u32,
+    /// Largest difference between a range's first and last ID
+    pub max_span: i64,
+}
+
+impl Params {
+    pub fn sized(size: usize) -> Self {
+        Params { ranges: size.max(1), max_digits: 10, max_span: (size as i64 * 1000).clamp(1, 100_000) }
+    }
+}
+
+/// A single line of comma-separated, non-overlapping `min-max` ranges of positive IDs.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut ranges: Vec<(i64, i64)> = Vec::new();
+    let mut attempts = 0;
+    while ranges.len() < params.ranges && attempts < params.ranges * 100 {
+        attempts += 1;
+
+        let digits = rng.int(1..=params.max_digits as i64) as u32;
+        let min_id = rng.int(10i64.pow(digits - 1)..=10i64.pow(digits) - 1);
+        let max_id = min_id + rng.int(0..=params.max_span);
+        if ranges.iter().all(|&(start, end)| max_id < start || min_id > end) {
+            ranges.push((min_id, max_id));
+        }
+    }
+
+    let ranges: Vec<String> = ranges.iter().map(|(min_id, max_id)| format!("{}-{}", min_id, max_id)).collect();
+    ranges.join(",") + "\n"
+}
@@ -0,0 +1,185 @@
+use aoc2025::params::NoParams;
+use aoc2025::parse;
+use aoc2025::num::{self, int};
+use aoc2025::{Example, Int, Overflow, ParseError, Records, Rng, SolveError, Solution};
+
+pub mod generate;
+pub mod reference;
+
+
+/// Sum of the IDs from `min_id` to `max_id` that are a block of digits repeated `reps` times,
+/// without listing them. Those with `digits` digits are the block times 1..01..01, so they're
+/// an arithmetic series.
+fn repeated_sum(min_id: i64, max_id: i64, reps: u32) -> Result<Int, Overflow> {
+    let mut total = int(0);
+    for digits in (min_id.ilog10() + 1)..=(max_id.ilog10() + 1) {
+        if !digits.is_multiple_of(reps) {
+            continue;
+        }
+
+        let low = min_id.max(10i64.pow(digits - 1));
+        let high = 10i64.checked_pow(digits).map_or(max_id, |limit| max_id.min(limit - 1));
+        let divisor = (0..digits).step_by((digits / reps) as usize).fold(0, |acc, exp| acc + 10i64.pow(exp));
+        let (first, last) = ((low - 1) / divisor + 1, high / divisor);
+        if first <= last {
+            // Blocks are at most 9 digits, so their sum can't overflow
+            let blocks = (first + last) * (last - first + 1) / 2;
+            total = num::add(&total, &num::mul(&int(divisor), &int(blocks))?)?;
+        }
+    }
+    return Ok(total);
+}
+
+/// The Möbius function of small `n`.
+fn mobius(n: u32) -> i64 {
+    let (mut rest, mut sign) = (n, 1);
+    let mut p = 2;
+    while rest > 1 {
+        if rest.is_multiple_of(p) {
+            rest /= p;
+            if rest.is_multiple_of(p) {
+                return 0;
+            }
+            sign = -sign;
+        }
+        p += 1;
+    }
+    return sign;
+}
+
+fn id_ranges(contents: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let line = parse::lines(contents)
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(contents, "a line of ID ranges"))?;
+
+    line.text.split(',')
+        .map(|range| {
+            let (min_str, max_str) = line.split_once(range, '-')?;
+            let min_id = line.parse::<i64>(min_str, "a positive ID")?;
+            let max_id = line.parse::<i64>(max_str, "a positive ID")?;
+
+            if min_id <= 0 {
+                return Err(line.error(min_str, "a positive ID"));
+            }
+            if max_id < min_id {
+                return Err(line.error(max_str, format!("an ID of at least {}", min_id)));
+            }
+            Ok((min_id, max_id))
+        })
+        .collect()
+}
+
+fn part1(ranges: &[(i64, i64)]) -> Result<Int, Overflow> {
+    return num::try_sum(ranges.iter().map(|&(min_id, max_id)| repeated_sum(min_id, max_id, 2)));
+}
+
+/// An ID that's a block repeated 4 times is also one repeated twice, so the sums for each
+/// number of repeats overlap. Weighting them by the Möbius function counts each ID once.
+fn part2(ranges: &[(i64, i64)]) -> Result<Int, Overflow> {
+    let mut total = int(0);
+    for &(min_id, max_id) in ranges {
+        for reps in 2..=(max_id.ilog10() + 1) {
+            total = match mobius(reps) {
+                0 => continue,
+                1 => num::sub(&total, &repeated_sum(min_id, max_id, reps)?)?,
+                _ => num::add(&total, &repeated_sum(min_id, max_id, reps)?)?,
+            };
+        }
+    }
+    return Ok(total);
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Gift Shop";
+    const LABELS: [&'static str; 2] = [
+        "Sum of invalid IDs",
+        "Sum of invalid IDs",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../fixtures/example.txt"),
+            part1: Some("1227775554"),
+            part2: Some("4174379265"),
+        },
+    ];
+    const RECORDS: Option<Records> = Some(Records::Separated(','));
+
+    /// Inclusive ranges of product IDs.
+    type Input = Vec<(i64, i64)>;
+    type Part1 = Int;
+    type Part2 = Int;
+    type Params = NoParams;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        id_ranges(contents)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(part2(input)?)
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day2>(1);
+    }
+
+    #[test]
+    fn p2_test() {
+        aoc2025::examples::check::<Day2>(2);
+    }
+
+    #[test]
+    fn matches_checking_every_id() {
+        let contents = "1-199999,95-115,998-1012,1188511880-1188511890,222220-222224\n";
+        let input = Day2::parse(contents).unwrap();
+        assert_eq!(part1(&input).unwrap(), reference::part1(contents));
+        assert_eq!(part2(&input).unwrap(), reference::part2(contents));
+    }
+
+    #[test]
+    fn matches_reference_on_generated_ranges() {
+        // Ranges long enough to cross from one digit length to the next
+        let params = generate::Params { ranges: 10, max_digits: 6, max_span: 100_000 };
+        for seed in 0..3 {
+            let contents = generate::generate(&mut Rng::new(seed), &params);
+            let input = Day2::parse(&contents).unwrap();
+            assert_eq!(part1(&input).unwrap(), reference::part1(&contents), "seed {}", seed);
+            assert_eq!(part2(&input).unwrap(), reference::part2(&contents), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn huge_range() {
+        let input = Day2::parse("1-9223372036854775807\n").unwrap();
+        if aoc2025::num::INT_NAME == "i64" {
+            assert!(part1(&input).is_err());
+        } else {
+            assert_eq!(part1(&input).unwrap().to_string(), "495495495540950040450040950");
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use aoc2025::num::int;
+use aoc2025::Int;
+
+fn ids(contents: &str) -> impl Iterator<Item = i64> + '_ {
+    contents.trim().split(',').flat_map(|range| {
+        let (min_id, max_id) = range.split_once('-').unwrap();
+        min_id.parse::<i64>().unwrap()..=max_id.parse::<i64>().unwrap()
+    })
+}
+
+/// Whether `id` is some sequence of digits repeated exactly `reps` times.
+fn repeated(id: i64, reps: usize) -> bool {
+    let digits = id.to_string();
+    if !digits.len().is_multiple_of(reps) {
+        return false;
+    }
+    let chunk = &digits[..digits.len() / reps];
+    return chunk.repeat(reps) == digits;
+}
+
+/// Checks every ID in every range.
+pub fn part1(contents: &str) -> Int {
+    ids(contents).filter(|&id| repeated(id, 2)).map(int).sum()
+}
+
+/// Checks every ID in every range, for every number of repeats.
+pub fn part2(contents: &str) -> Int {
+    ids(contents)
+        .filter(|&id| (2..=id.to_string().len()).any(|reps| repeated(id, reps)))
+        .map(int)
+        .sum()
+}
@@ -0,0 +1,29 @@
+use aoc2025::Rng;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub banks: usize,
+    /// Batteries per bank. Part 2 turns on 12, so at least that many.
+    pub batteries: usize,
+}
+
+impl Params {
+    pub fn sized(size: usize) -> Self {
+        Params { banks: size.max(1), batteries: 100 }
+    }
+}
+
+/// One bank of joltage digits (1-9) per line.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    assert!(params.batteries >= 12, "Banks need at least 12 batteries");
+
+    (0..params.banks)
+        .map(|_| {
+            let bank: String = (0..params.batteries)
+                .map(|_| char::from_digit(rng.int(1..=9) as u32, 10).unwrap())
+                .collect();
+            bank + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,135 @@
+use aoc2025::parse::{self, Line, Span};
+use aoc2025::num::{self, int};
+use aoc2025::{Example, Int, Overflow, ParseError, Records, Rng, SolveError, Solution};
+use serde::Deserialize;
+
+pub mod generate;
+pub mod reference;
+
+/// A line of battery joltages, kept with its span since the parts need banks of different lengths.
+pub struct Bank {
+    span: Span,
+    joltages: Vec<i64>,
+}
+
+/// How many batteries each part turns on in every bank.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Digits {
+    pub part1: usize,
+    pub part2: usize,
+}
+
+impl Default for Digits {
+    fn default() -> Self {
+        Digits { part1: 2, part2: 12 }
+    }
+}
+
+/// The banks, with how many batteries to turn on in each.
+pub struct Lobby {
+    banks: Vec<Bank>,
+    digits: Digits,
+}
+
+fn bank(line: Line) -> Result<Bank, ParseError> {
+    line.check_chars(line.text, |c| c.is_ascii_digit(), "a digit")?;
+    let joltages = line.text.chars().map(|c| c.to_digit(10).unwrap() as i64).collect();
+    Ok(Bank { span: line.span(line.text), joltages })
+}
+
+fn max_val(joltages: &[i64], digs: usize) -> Result<Int, Overflow> {
+    let mut max_val = int(0);
+    let mut ix = 0;
+    for dig in 1..=digs {
+        let (new_ix, d) = joltages[ix..(joltages.len() - (digs - dig))]
+            .iter()
+            .enumerate()
+            .max_by_key(|&(i, &d)| (d, -(i as isize)))
+            .map(|(i, &d)| (i + ix, d))
+            .unwrap();
+        max_val = num::add(&num::mul(&max_val, &int(10))?, &int(d))?;
+        ix = new_ix + 1;
+    }
+    Ok(max_val)
+}
+
+fn total_joltage(banks: &[Bank], digs: usize) -> Result<Int, SolveError> {
+    let mut total = int(0);
+    for bank in banks {
+        if bank.joltages.len() < digs {
+            return Err(bank.span.error(format!("a bank of at least {} batteries", digs)).into());
+        }
+        total = num::add(&total, &max_val(&bank.joltages, digs)?)?;
+    }
+    Ok(total)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Lobby";
+    const LABELS: [&'static str; 2] = [
+        "Total Joltage",
+        "Total Joltage",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../fixtures/example.txt"),
+            part1: Some("357"),
+            part2: Some("3121910778619"),
+        },
+    ];
+    const RECORDS: Option<Records> = Some(Records::Lines);
+
+    type Input = Lobby;
+    type Part1 = Int;
+    type Part2 = Int;
+    type Params = Digits;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(contents, &Digits::default())
+    }
+
+    fn parse_with(contents: &str, digits: &Self::Params) -> Result<Self::Input, ParseError> {
+        let banks = parse::lines(contents).map(bank).collect::<Result<_, ParseError>>()?;
+        Ok(Lobby { banks, digits: digits.clone() })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        total_joltage(&input.banks, input.digits.part1)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        total_joltage(&input.banks, input.digits.part2)
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day3>(1);
+    }
+
+    #[test]
+    fn p2_test() {
+        aoc2025::examples::check::<Day3>(2);
+    }
+}
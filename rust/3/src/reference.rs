@@ -0,0 +1,41 @@
+use aoc2025::num::int;
+use aoc2025::Int;
+
+/// Tries every pair of batteries.
+pub fn part1(contents: &str) -> Int {
+    contents.lines()
+        .map(|bank| {
+            let digits: Vec<i64> = bank.chars().map(|c| c.to_digit(10).unwrap() as i64).collect();
+            let mut best = 0;
+            for i in 0..digits.len() {
+                for j in (i + 1)..digits.len() {
+                    best = best.max(digits[i] * 10 + digits[j]);
+                }
+            }
+            int(best)
+        })
+        .sum()
+}
+
+/// Builds the best joltage of every length from every suffix of the bank.
+pub fn part2(contents: &str) -> Int {
+    contents.lines()
+        .map(|bank| {
+            let digits: Vec<i64> = bank.chars().map(|c| c.to_digit(10).unwrap() as i64).collect();
+            // best[i][k]: largest k digit joltage using batteries from i onwards
+            let mut best: Vec<Vec<Option<i64>>> = vec![vec![None; 13]; digits.len() + 1];
+            for i in (0..=digits.len()).rev() {
+                best[i][0] = Some(0);
+                if i == digits.len() {
+                    continue;
+                }
+                for k in 1..=12 {
+                    let skip = best[i + 1][k];
+                    let take = best[i + 1][k - 1].map(|rest| digits[i] * 10i64.pow(k as u32 - 1) + rest);
+                    best[i][k] = skip.max(take);
+                }
+            }
+            int(best[0][12].unwrap())
+        })
+        .sum()
+}
@@ -0,0 +1,28 @@
+use aoc2025::Rng;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub width: usize,
+    pub height: usize,
+    /// Chance of each cell holding a roll of paper
+    pub density: f64,
+}
+
+impl Params {
+    pub fn sized(size: usize) -> Self {
+        Params { width: size.max(1), height: size.max(1), density: 0.65 }
+    }
+}
+
+/// A rectangular grid of `@` (rolls) and `.`.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    (0..params.height)
+        .map(|_| {
+            let row: String = (0..params.width)
+                .map(|_| if rng.chance(params.density) { '@' } else { '.' })
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
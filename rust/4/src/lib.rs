@@ -0,0 +1,147 @@
+use aoc2025::params::NoParams;
+use aoc2025::parse;
+use aoc2025::{Example, ParseError, Rng, SolveError, Solution};
+use convolutions_rs::convolutions::*;
+use ndarray::*;
+use convolutions_rs::Padding;
+
+pub mod generate;
+pub mod reference;
+
+fn rolls(contents: &str) -> Result<Array3<f32>, ParseError> {
+    let width = contents.lines().next().map_or(0, str::len);
+    if width == 0 {
+        return Err(ParseError::end_of_input(contents, "a row of the grid"));
+    }
+
+    let mut cells: Vec<f32> = Vec::new();
+    for line in parse::lines(contents) {
+        line.check_chars(line.text, |c| c == '@' || c == '.', "'@' or '.'")?;
+        if line.text.len() != width {
+            return Err(line.error(line.text, format!("a row {} cells wide", width)));
+        }
+        cells.extend(line.text.chars().map(|b| ((b == '@') as u8) as f32));
+    }
+
+    Ok(Array::from_shape_vec((1, cells.len() / width, width), cells).unwrap())
+}
+
+/// Counts each cell's neighbouring rolls.
+fn neighbours() -> Array4<f32> {
+    Array::from_shape_vec(
+        (1, 1, 3, 3),
+        vec![
+            1., 1., 1.,
+            1., 0., 1.,
+            1., 1., 1.,
+        ],
+    ).unwrap()
+}
+
+fn part1(rolls: &Array3<f32>) -> i64 {
+    let kernel = neighbours();
+
+    let conv_layer = ConvolutionLayer::new(kernel.clone(), None, 1, Padding::Same);
+    let output = conv_layer.convolve(rolls);
+    let input_arr = (rolls - 1.) * (2. * kernel.sum());
+
+    return (output + input_arr).into_iter().filter(|&x| (0. ..4.).contains(&x)).count() as i64;
+}
+
+fn part2(rolls: &Array3<f32>) -> i64 {
+    let mut input_arr: Array3<f32> = rolls.clone();
+    let kernel = neighbours();
+
+    let conv_layer = ConvolutionLayer::new(kernel.clone(), None, 1, Padding::Same);
+
+    let mut count: i64 = 0;
+    loop {
+        let mut output = conv_layer.convolve(&input_arr);
+        input_arr = (input_arr - 1.) * (2. * kernel.sum());
+        output += &input_arr;
+
+        let modify_arr: Array3<f32> = output.mapv(|x| if (0. ..4.).contains(&x) {0.} else {1.});
+        let modify_count = modify_arr.iter().filter(|&&x| x == 0.).count() as i64;
+
+        if modify_count == 0 {
+            break;
+        }
+        
+        count += modify_count;
+        input_arr = input_arr / (2. * kernel.sum()) + modify_arr;
+    }
+
+    return count;
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Printing Department";
+    const LABELS: [&'static str; 2] = [
+        "Number of reachable rolls",
+        "Number of reachable rolls",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../fixtures/example.txt"),
+            part1: Some("13"),
+            part2: Some("43"),
+        },
+    ];
+
+    /// The grid, with 1 where there's a roll of paper.
+    type Input = Array3<f32>;
+    type Part1 = i64;
+    type Part2 = i64;
+    type Params = NoParams;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        rolls(contents)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(part2(input))
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day4>(1);
+    }
+
+    #[test]
+    fn p2_test() {
+        aoc2025::examples::check::<Day4>(2);
+    }
+
+    #[test]
+    fn blank_input() {
+        for contents in ["", "\n", "\n\n", "\r\n"] {
+            let solved = aoc2025::Puzzle::of::<Day4>().solve(contents, &[1, 2]);
+            assert!(matches!(solved, Err(SolveError::Parse(ref err)) if err.expected == "a row of the grid"), "{:?}", contents);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+fn grid(contents: &str) -> Vec<Vec<bool>> {
+    contents.lines().map(|line| line.chars().map(|c| c == '@').collect()).collect()
+}
+
+/// Rolls with fewer than 4 rolls among their 8 neighbours.
+fn accessible(grid: &[Vec<bool>]) -> Vec<(usize, usize)> {
+    let mut accessible = Vec::new();
+    for y in 0..grid.len() {
+        for x in 0..grid[y].len() {
+            if !grid[y][x] {
+                continue;
+            }
+
+            let mut neighbours = 0;
+            for row in &grid[y.saturating_sub(1)..=(y + 1).min(grid.len() - 1)] {
+                for nx in x.saturating_sub(1)..=x + 1 {
+                    if row.get(nx) == Some(&true) {
+                        neighbours += 1;
+                    }
+                }
+            }
+            // The loop above counted the roll itself
+            neighbours -= 1;
+            if neighbours < 4 {
+                accessible.push((y, x));
+            }
+        }
+    }
+    return accessible;
+}
+
+/// Counts neighbours cell by cell.
+pub fn part1(contents: &str) -> i64 {
+    accessible(&grid(contents)).len() as i64
+}
+
+/// Removes every accessible roll, over and over until none are left.
+pub fn part2(contents: &str) -> i64 {
+    let mut grid = grid(contents);
+    let mut count = 0;
+    loop {
+        let removed = accessible(&grid);
+        if removed.is_empty() {
+            return count;
+        }
+        for &(y, x) in &removed {
+            grid[y][x] = false;
+        }
+        count += removed.len() as i64;
+    }
+}
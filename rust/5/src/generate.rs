@@ -0,0 +1,41 @@
+use aoc2025::Rng;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub ranges: usize,
+    pub ids: usize,
+    pub max_id: i64,
+}
+
+impl Params {
+    pub fn sized(size: usize) -> Self {
+        Params { ranges: size.max(1), ids: size.max(1), max_id: 1_000_000_000_000_000 }
+    }
+}
+
+/// Possibly overlapping `start-end` ranges, a blank line, then one ID per line,
+/// about half of which fall in some range.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let max_span = params.max_id / params.ranges as i64;
+    let ranges: Vec<(i64, i64)> = (0..params.ranges)
+        .map(|_| {
+            let start = rng.int(1..=params.max_id);
+            (start, (start + rng.int(0..=max_span)).min(params.max_id))
+        })
+        .collect();
+
+    let ids = (0..params.ids).map(|_| {
+        if rng.chance(0.5) {
+            let &(start, end) = rng.choose(&ranges);
+            rng.int(start..=end)
+        } else {
+            rng.int(1..=params.max_id)
+        }
+    });
+
+    let mut lines: Vec<String> = ranges.iter().map(|(start, end)| format!("{}-{}", start, end)).collect();
+    lines.push(String::new());
+    lines.extend(ids.map(|id| id.to_string()));
+    lines.join("\n") + "\n"
+}
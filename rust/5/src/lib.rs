@@ -0,0 +1,149 @@
+use aoc2025::params::NoParams;
+use aoc2025::parse;
+use aoc2025::num::{self, int};
+use aoc2025::{Example, Int, Overflow, ParseError, Records, Rng, SolveError, Solution};
+
+pub mod generate;
+pub mod reference;
+
+fn sections(contents: &str) -> Result<(&str, &str), ParseError> {
+    contents.split_once("\n\n")
+        .ok_or_else(|| ParseError::end_of_input(contents, "a blank line between the ranges and the IDs"))
+}
+
+/// Fresh ingredient ranges, merged so none overlap or touch, and the available ingredient IDs.
+pub struct Inventory {
+    ranges: Vec<(i64, i64)>,
+    ids: Vec<i64>,
+}
+
+fn inventory(contents: &str) -> Result<Inventory, ParseError> {
+    let (ranges_str, ids_str) = sections(contents)?;
+
+    let mut ranges: Vec<(i64, i64)> = parse::section_lines(contents, ranges_str)
+        .map(|line| {
+            let (start_str, end_str) = line.split_once(line.text, '-')?;
+            let start = line.parse::<i64>(start_str, "an ingredient ID")?;
+            let end = line.parse::<i64>(end_str, "an ingredient ID")?;
+            if end < start {
+                return Err(line.error(end_str, "an end of at least the start"));
+            }
+            Ok((start, end))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    ranges.sort_by_key(|a| a.0);
+
+    let mut merged_ranges: Vec<(i64, i64)> = Vec::new();
+    for range in ranges {
+        if let Some(last) = merged_ranges.last_mut() {
+            if range.0 <= last.1.saturating_add(1) {
+                last.1 = last.1.max(range.1);
+            } else {
+                merged_ranges.push(range);
+            }
+        } else {
+            merged_ranges.push(range);
+        }
+    }
+
+    let ids: Vec<i64> = parse::section_lines(contents, ids_str)
+        .map(|line| line.parse::<i64>(line.text, "an ingredient ID"))
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok(Inventory { ranges: merged_ranges, ids })
+}
+
+fn part1(inventory: &Inventory) -> i64 {
+    return inventory.ids.iter()
+        .filter(|id| {
+            for range in &inventory.ranges {
+                if **id >= range.0 && **id <= range.1 {
+                    return true;
+                }
+            }
+            return false;
+        })
+        .count() as i64;
+}
+
+fn part2(inventory: &Inventory) -> Result<Int, Overflow> {
+    return num::try_sum(inventory.ranges.iter()
+        .map(|(start, end)| num::add(&num::sub(&int(*end), &int(*start))?, &int(1))));
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Cafeteria";
+    const LABELS: [&'static str; 2] = [
+        "Number of available fresh ingredients",
+        "Number of possible fresh ingredients",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../fixtures/example.txt"),
+            part1: Some("3"),
+            part2: Some("14"),
+        },
+    ];
+    const RECORDS: Option<Records> = Some(Records::Lines);
+
+    type Input = Inventory;
+    type Part1 = i64;
+    type Part2 = Int;
+    type Params = NoParams;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        inventory(contents)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(part2(input)?)
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day5>(1);
+    }
+
+    #[test]
+    fn p2_test() {
+        aoc2025::examples::check::<Day5>(2);
+    }
+
+    #[test]
+    fn range_up_to_max() {
+        let inventory = Day5::parse("1-9223372036854775807\n5-6\n\n1\n").unwrap();
+        assert_eq!(inventory.ranges, vec![(1, i64::MAX)]);
+    }
+
+    #[test]
+    fn reversed_range() {
+        let err = Day5::parse("10-3\n\n1\n").err().unwrap();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (1, 4, "an end of at least the start"));
+    }
+}
@@ -0,0 +1,36 @@
+use aoc2025::num::int;
+use aoc2025::Int;
+
+fn ranges(contents: &str) -> Vec<(i64, i64)> {
+    contents.split("\n\n").next().unwrap().lines()
+        .map(|line| {
+            let (start, end) = line.split_once('-').unwrap();
+            (start.parse().unwrap(), end.parse().unwrap())
+        })
+        .collect()
+}
+
+/// Checks each ID against every range.
+pub fn part1(contents: &str) -> i64 {
+    let ranges = ranges(contents);
+    contents.split("\n\n").nth(1).unwrap().lines()
+        .map(|line| line.parse::<i64>().unwrap())
+        .filter(|id| ranges.iter().any(|(start, end)| start <= id && id <= end))
+        .count() as i64
+}
+
+/// Splits the IDs at every range boundary and counts the pieces some range covers.
+pub fn part2(contents: &str) -> Int {
+    let ranges = ranges(contents);
+    let mut bounds: Vec<i64> = ranges.iter().flat_map(|&(start, end)| [start, end + 1]).collect();
+    bounds.sort();
+    bounds.dedup();
+
+    let mut count = int(0);
+    for piece in bounds.windows(2) {
+        if ranges.iter().any(|&(start, end)| start <= piece[0] && piece[1] - 1 <= end) {
+            count += int(piece[1]) - int(piece[0]);
+        }
+    }
+    return count;
+}
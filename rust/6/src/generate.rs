@@ -0,0 +1,42 @@
+use aoc2025::Rng;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub problems: usize,
+    /// Numbers in each problem
+    pub rows: usize,
+    pub max_digits: usize,
+}
+
+impl Params {
+    /// Products of four 4 digit numbers are up to 10^16, so 500 problems keeps the total within `i64`.
+    pub fn sized(size: usize) -> Self {
+        Params { problems: size.clamp(1, 500), rows: 4, max_digits: 4 }
+    }
+}
+
+/// A worksheet of problems side by side, separated by a column of spaces. Each problem's
+/// numbers are all left or all right aligned, and its operator sits under its first column.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut lines: Vec<Vec<String>> = vec![Vec::new(); params.rows + 1];
+
+    for _ in 0..params.problems {
+        let nums: Vec<String> = (0..params.rows)
+            .map(|_| {
+                (0..rng.int(1..=params.max_digits as i64))
+                    .map(|_| char::from_digit(rng.int(1..=9) as u32, 10).unwrap())
+                    .collect()
+            })
+            .collect();
+        let width = nums.iter().map(String::len).max().unwrap();
+        let left = rng.chance(0.5);
+
+        for (line, num) in lines.iter_mut().zip(nums) {
+            line.push(if left { format!("{:<width$}", num) } else { format!("{:>width$}", num) });
+        }
+        lines[params.rows].push(format!("{:<width$}", rng.choose(&['+', '*'])));
+    }
+
+    lines.iter().map(|line| line.join(" ") + "\n").collect()
+}
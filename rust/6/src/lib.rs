@@ -0,0 +1,176 @@
+use aoc2025::params::NoParams;
+use aoc2025::parse::{self, Line};
+use aoc2025::num::{self, int};
+use aoc2025::{Example, Int, Overflow, ParseError, Records, Rng, SolveError, Solution};
+use std::collections::HashSet;
+
+pub mod generate;
+pub mod reference;
+
+fn cell<'a>(line: Line<'a>, start_col: usize, end_col: usize) -> Result<&'a str, ParseError> {
+    line.text.get(start_col..end_col)
+        .ok_or_else(|| line.error_at_end(format!("a row at least {} columns wide", end_col)))
+}
+
+fn operator(line: Line, start_col: usize, end_col: usize) -> Result<char, ParseError> {
+    let op = cell(line, start_col, end_col)?.trim();
+    match op {
+        "+" => Ok('+'),
+        "*" => Ok('*'),
+        _ => Err(line.error(op, "'+' or '*'")),
+    }
+}
+
+/// One problem on the worksheet, with its numbers read both along the rows and down the columns.
+pub struct Problem {
+    operator: char,
+    rows: Vec<i64>,
+    columns: Vec<i64>,
+}
+
+impl Problem {
+    fn solve(&self, nums: &[i64]) -> Result<Int, Overflow> {
+        match self.operator {
+            '+' => num::sum(nums.iter().map(|&n| int(n))),
+            '*' => num::product(nums.iter().map(|&n| int(n))),
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn worksheet(contents: &str) -> Result<Vec<Problem>, ParseError> {
+    let width = contents.lines().next()
+        .ok_or_else(|| ParseError::end_of_input(contents, "a worksheet"))?
+        .len();
+    let rows: Vec<Line> = parse::lines(contents).collect();
+    let operators = *rows.last().unwrap();
+    let mut columns: HashSet<usize> = HashSet::from_iter(0..=width);
+    for line in contents.lines() {
+        for (i, c) in line.chars().enumerate() {
+            if c != ' ' {
+                columns.remove(&i);
+            }
+        }
+    }
+    let mut sorted_columns: Vec<&usize> = Vec::from_iter(columns.iter());
+    sorted_columns.sort();
+
+    let mut problems: Vec<Problem> = Vec::new();
+    let mut start_col: Option<usize> = None;
+    for end_col in sorted_columns {
+        let row_nums: Vec<i64> = rows.iter()
+            .rev()
+            .skip(1)
+            .map(|line| line.parse::<i64>(cell(*line, start_col.unwrap_or(0), *end_col)?.trim(), "a number"))
+            .collect::<Result<_, ParseError>>()?;
+
+        let column_nums: Vec<i64> = (start_col.unwrap_or(0)..*end_col)
+            .map(|i| {
+                let digits = rows.iter()
+                    .rev()
+                    .skip(1)
+                    .map(|line| cell(*line, i, i + 1))
+                    .collect::<Result<String, ParseError>>()?;
+                rows[0].parse::<i64>(
+                    &digits.chars().filter(|&c| c != ' ').rev().collect::<String>(),
+                    "a number in this column",
+                ).map_err(|err| ParseError { column: i + 1, ..err })
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        problems.push(Problem {
+            operator: operator(operators, start_col.unwrap_or(0), *end_col)?,
+            rows: row_nums,
+            columns: column_nums,
+        });
+        start_col = Some(end_col + 1);
+    }
+
+    return Ok(problems);
+}
+
+fn part1(problems: &[Problem]) -> Result<Int, Overflow> {
+    return num::try_sum(problems.iter().map(|problem| problem.solve(&problem.rows)));
+}
+
+fn part2(problems: &[Problem]) -> Result<Int, Overflow> {
+    return num::try_sum(problems.iter().map(|problem| problem.solve(&problem.columns)));
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Trash Compactor";
+    const LABELS: [&'static str; 2] = [
+        "Sum of problem answers",
+        "Sum of problem answers",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../fixtures/example.txt"),
+            part1: Some("4277556"),
+            part2: Some("3263827"),
+        },
+    ];
+    const RECORDS: Option<Records> = Some(Records::Columns);
+
+    type Input = Vec<Problem>;
+    type Part1 = Int;
+    type Part2 = Int;
+    type Params = NoParams;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        worksheet(contents)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(part2(input)?)
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day6>(1);
+    }
+
+    #[test]
+    fn p2_test() {
+        aoc2025::examples::check::<Day6>(2);
+    }
+
+    #[test]
+    fn overflow_is_reported() {
+        // 2^40 * 2^40 doesn't fit in an i64
+        let contents = "1099511627776\n1099511627776\n*            \n";
+        let solved = aoc2025::Puzzle::of::<Day6>().solve(contents, &[1]);
+
+        if aoc2025::num::INT_NAME == "i64" {
+            let err = solved.unwrap_err();
+            assert!(matches!(err, SolveError::Overflow(ref overflow) if overflow.day == 6 && overflow.part == 1), "{}", err);
+        } else {
+            assert_eq!(solved.unwrap().parts[0].answer.to_string(), "1208925819614629174706176");
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use aoc2025::num::int;
+use aoc2025::Int;
+
+/// Each problem's operator and its block of the worksheet, one string per number row.
+fn problems(contents: &str) -> Vec<(char, Vec<String>)> {
+    let rows: Vec<Vec<char>> = contents.lines().map(|line| line.chars().collect()).collect();
+    let width = rows.iter().map(Vec::len).max().unwrap();
+    let at = |row: &Vec<char>, x: usize| *row.get(x).unwrap_or(&' ');
+
+    let mut problems = Vec::new();
+    let mut start = 0;
+    for x in 0..=width {
+        if x < width && rows.iter().any(|row| at(row, x) != ' ') {
+            continue;
+        }
+        if x > start {
+            let (ops, nums) = rows.split_last().unwrap();
+            let op = (start..x).map(|x| at(ops, x)).find(|&c| c != ' ').unwrap();
+            let block = nums.iter().map(|row| (start..x).map(|x| at(row, x)).collect()).collect();
+            problems.push((op, block));
+        }
+        start = x + 1;
+    }
+    return problems;
+}
+
+fn apply(op: char, nums: impl Iterator<Item = i64>) -> Int {
+    let nums = nums.map(int);
+    if op == '+' { nums.sum() } else { nums.product() }
+}
+
+/// Reads each row of a problem as a number.
+pub fn part1(contents: &str) -> Int {
+    problems(contents).iter()
+        .map(|(op, block)| apply(*op, block.iter().map(|row| row.trim().parse::<i64>().unwrap())))
+        .sum()
+}
+
+/// Reads each column of a problem, top to bottom, as a number.
+pub fn part2(contents: &str) -> Int {
+    problems(contents).iter()
+        .map(|(op, block)| {
+            let width = block[0].len();
+            apply(*op, (0..width).map(|x| {
+                let digits: String = block.iter().map(|row| row.as_bytes()[x] as char).filter(|&c| c != ' ').collect();
+                digits.parse::<i64>().unwrap()
+            }))
+        })
+        .sum()
+}
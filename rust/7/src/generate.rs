@@ -0,0 +1,41 @@
+use aoc2025::Rng;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// Rows of splitters below the start
+    pub rows: usize,
+    /// Chance of each reachable position holding a splitter
+    pub density: f64,
+}
+
+impl Params {
+    /// Timelines can double with every row, so rows are capped at the puzzle's 70 to keep part 2 within `i64`.
+    pub fn sized(size: usize) -> Self {
+        Params { rows: size.clamp(1, 70), density: 0.5 }
+    }
+}
+
+/// A manifold like the puzzle's: `S` centered in the top row, then alternating blank and
+/// splitter rows, ending with a blank one. Splitters are only placed where a beam could be,
+/// so none sit on the edges, and the first row always splits.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let width = 2 * params.rows + 1;
+    let center = params.rows;
+    let blank = ".".repeat(width) + "\n";
+
+    let mut manifold = format!("{}S{}\n", ".".repeat(center), ".".repeat(center));
+    for row in 1..=params.rows {
+        manifold += &blank;
+
+        let splitters: String = (0..width)
+            .map(|x| {
+                let offset = x.abs_diff(center);
+                let reachable = offset < row && offset % 2 == (row - 1) % 2;
+                if reachable && (row == 1 || rng.chance(params.density)) { '^' } else { '.' }
+            })
+            .collect();
+        manifold += &(splitters + "\n");
+    }
+    manifold + &blank
+}
@@ -0,0 +1,206 @@
+use aoc2025::params::NoParams;
+use aoc2025::parse;
+use aoc2025::num::{self, int};
+use aoc2025::{Example, Int, Overflow, ParseError, Rng, SolveError, Solution};
+use std::collections::*;
+
+pub mod generate;
+pub mod reference;
+
+/// Splitter positions and the beam's start, counting only the even lines (the odd ones are empty).
+pub struct Manifold {
+    splitters: HashSet<(usize, usize)>,
+    start: (usize, usize),
+    min_x: usize,
+    max_x: usize,
+    max_y: usize,
+}
+
+fn manifold(contents: &str) -> Result<Manifold, ParseError> {
+    let mut splitters: HashSet<(usize, usize)> = HashSet::new();
+    let mut min_x = usize::MAX;
+    let mut max_x = 0;
+    let mut max_y = 0;
+    let mut start: Option<(usize, usize)> = None;
+    let width = contents.lines().next().map_or(0, str::len);
+    for (y, line) in parse::lines(contents).step_by(2).enumerate() {
+        max_y = y;
+        line.check_chars(line.text, |c| matches!(c, 'S' | '^' | '.'), "'.', '^' or 'S'")?;
+        if line.text.len() != width {
+            return Err(line.error(line.text, format!("a row {} cells wide", width)));
+        }
+        for (x, c) in line.text.char_indices() {
+            if x < min_x {
+                min_x = x;
+            }
+            if x > max_x {
+                max_x = x;
+            }
+
+            match c {
+                'S' => {
+                    start = Some((x, y));
+                },
+                '^' => {
+                    if x == 0 || x + 1 == width {
+                        return Err(line.error(&line.text[x..=x], "a splitter with room for a beam on both sides"));
+                    }
+                    splitters.insert((x, y));
+                },
+                '.' => {},
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    let start = start.ok_or_else(|| ParseError::end_of_input(contents, "an 'S' marking the start"))?;
+    Ok(Manifold { splitters, start, min_x, max_x, max_y })
+}
+
+/// Splitters only on the even lines `manifold` reads, exactly one 'S', and rows of the same width.
+fn lint(contents: &str) -> Vec<ParseError> {
+    let mut problems: Vec<ParseError> = Vec::new();
+    let width = contents.lines().next().map_or(0, str::len);
+    let mut start: Option<usize> = None;
+    for (y, line) in parse::lines(contents).enumerate() {
+        if line.text.len() != width {
+            problems.push(line.error(line.text, format!("a row {} cells wide", width)));
+        }
+        for (x, c) in line.text.char_indices() {
+            let cell = &line.text[x..x + c.len_utf8()];
+            match c {
+                '^' | 'S' if y % 2 == 1 => {
+                    problems.push(line.error(cell, "'.' on a line between rows, which is skipped"));
+                }
+                'S' => match start {
+                    Some(first) => problems.push(line.error(cell, format!("only the 'S' on line {}", first))),
+                    None => start = Some(line.number),
+                },
+                _ => {}
+            }
+        }
+    }
+
+    if start.is_none() {
+        problems.push(ParseError::end_of_input(contents, "an 'S' marking the start"));
+    }
+    problems
+}
+
+fn part1(manifold: &Manifold) -> i64 {
+    let mut open_list: VecDeque<(usize, usize)> = VecDeque::from([manifold.start]);
+    let mut closed_list: HashSet<(usize, usize)> = HashSet::new();
+    
+    let mut split_count = 0;
+    while let Some(current) = open_list.pop_front() {
+        if closed_list.contains(&current) || current.1 > manifold.max_y {
+            continue;
+        }
+        closed_list.insert(current);
+
+        let new_pos = (current.0, current.1 + 1);
+        if manifold.splitters.contains(&new_pos) {
+            split_count += (!closed_list.contains(&new_pos)) as i64;
+            open_list.push_back((new_pos.0 - 1, new_pos.1));
+            open_list.push_back((new_pos.0 + 1, new_pos.1));
+        } else {
+            open_list.push_back(new_pos);
+        }
+    }
+
+    return split_count;
+}
+
+fn part2(manifold: &Manifold) -> Result<Int, Overflow> {
+    let Manifold { splitters, start, min_x, max_x, max_y } = manifold;
+
+    let mut path_counts: Vec<Int> = vec![int(1); max_x - min_x + 1];
+    
+    for y in (0..=*max_y).rev() {
+        let p_path_counts = path_counts.clone();
+        for splitter in splitters.iter().filter(|(_, sy)| *sy == y) {
+            let (sx, _) = *splitter;
+            let lix = sx - min_x - 1;
+            let rix = sx - min_x + 1;
+
+            path_counts[sx - min_x] = num::add(&p_path_counts[lix], &p_path_counts[rix])?;
+        }
+    }
+
+    return Ok(path_counts.swap_remove(start.0 - min_x));
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "Laboratories";
+    const LABELS: [&'static str; 2] = [
+        "Number of times beam is split",
+        "Number of timelines",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../fixtures/example.txt"),
+            part1: Some("21"),
+            part2: Some("40"),
+        },
+    ];
+
+    type Input = Manifold;
+    type Part1 = i64;
+    type Part2 = Int;
+    type Params = NoParams;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        manifold(contents)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(part2(input)?)
+    }
+
+    fn lint(contents: &str) -> Vec<ParseError> {
+        lint(contents)
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day7>(1);
+    }
+
+    #[test]
+    fn p2_test() {
+        aoc2025::examples::check::<Day7>(2);
+    }
+
+    #[test]
+    fn lint_test() {
+        let problems = Day7::lint("..S..\n..^..\n.^S^.\n....\n");
+        let found: Vec<(usize, usize)> = problems.iter().map(|err| (err.line, err.column)).collect();
+        assert_eq!(found, vec![(2, 3), (3, 3), (4, 1)]);
+        assert!(Day7::lint(Day7::EXAMPLES[0].input).is_empty());
+    }
+}
@@ -0,0 +1,47 @@
+use aoc2025::num::int;
+use aoc2025::Int;
+use std::collections::BTreeSet;
+
+/// The start column and each row of the manifold as characters.
+fn manifold(contents: &str) -> (usize, Vec<Vec<char>>) {
+    let rows: Vec<Vec<char>> = contents.lines().map(|line| line.chars().collect()).collect();
+    let start = rows[0].iter().position(|&c| c == 'S').unwrap();
+    (start, rows)
+}
+
+/// Moves the set of beams down a row at a time, counting splitters they hit.
+pub fn part1(contents: &str) -> i64 {
+    let (start, rows) = manifold(contents);
+    let mut beams: BTreeSet<usize> = BTreeSet::from([start]);
+    let mut splits = 0;
+    for row in &rows[1..] {
+        let mut next = BTreeSet::new();
+        for &x in &beams {
+            if row[x] == '^' {
+                splits += 1;
+                next.insert(x - 1);
+                next.insert(x + 1);
+            } else {
+                next.insert(x);
+            }
+        }
+        beams = next;
+    }
+    return splits;
+}
+
+fn timelines(rows: &[Vec<char>], y: usize, x: usize) -> Int {
+    if y == rows.len() {
+        return int(1);
+    }
+    if rows[y][x] == '^' {
+        return timelines(rows, y + 1, x - 1) + timelines(rows, y + 1, x + 1);
+    }
+    return timelines(rows, y + 1, x);
+}
+
+/// Follows every timeline separately.
+pub fn part2(contents: &str) -> Int {
+    let (start, rows) = manifold(contents);
+    timelines(&rows, 1, start)
+}
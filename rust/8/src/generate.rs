@@ -0,0 +1,40 @@
+use aoc2025::Rng;
+use std::collections::HashSet;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// Part 1 connects the 10 closest pairs of 20 boxes (the example) and 1000 pairs
+    /// otherwise, so this is either 20 or at least 46.
+    pub boxes: usize,
+    pub extent: i64,
+}
+
+impl Params {
+    pub fn sized(size: usize) -> Self {
+        Params { boxes: if size <= 20 { 20 } else { size.max(46) }, extent: 100_000 }
+    }
+}
+
+/// One `x,y,z` junction box per line. Every pair of boxes is a different distance apart,
+/// so which pairs connect first doesn't depend on iteration order.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    assert!(params.boxes == 20 || params.boxes >= 46, "Need 20 or at least 46 boxes");
+
+    let mut boxes: Vec<(i64, i64, i64)> = Vec::new();
+    let mut distances: HashSet<i64> = HashSet::new();
+    while boxes.len() < params.boxes {
+        let point = (rng.int(0..=params.extent), rng.int(0..=params.extent), rng.int(0..=params.extent));
+        let new_distances: Vec<i64> = boxes.iter()
+            .map(|b| (b.0 - point.0).pow(2) + (b.1 - point.1).pow(2) + (b.2 - point.2).pow(2))
+            .collect();
+
+        let unique: HashSet<i64> = new_distances.iter().copied().collect();
+        if unique.len() == new_distances.len() && !new_distances.iter().any(|d| *d == 0 || distances.contains(d)) {
+            distances.extend(new_distances);
+            boxes.push(point);
+        }
+    }
+
+    boxes.iter().map(|(x, y, z)| format!("{},{},{}\n", x, y, z)).collect()
+}
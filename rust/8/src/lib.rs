@@ -0,0 +1,365 @@
+use aoc2025::parse::{self, Line, Span};
+use aoc2025::num::{self, int};
+use aoc2025::{Example, Int, Overflow, ParseError, Records, Rng, SolveError, Solution};
+use std::collections::*;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use serde::Deserialize;
+
+pub mod generate;
+pub mod reference;
+
+
+#[derive(Debug, Clone)]
+struct Pair {
+    a: (i64, i64, i64),
+    b: (i64, i64, i64),
+    dist: f64,
+}
+
+impl Pair {
+    fn new(a: (i64, i64, i64), b: (i64, i64, i64)) -> Self {
+        Pair { 
+            a, 
+            b, 
+            // In floats, since coordinates far enough apart overflow an i64 when squared
+            dist: f64::sqrt([a.0.abs_diff(b.0), a.1.abs_diff(b.1), a.2.abs_diff(b.2)].iter().map(|&d| (d as f64).powi(2)).sum())
+        }
+    }
+}
+
+impl Ord for Pair {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.dist.total_cmp(&other.dist)
+    }
+}
+
+impl PartialOrd for Pair {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Pair {
+    fn eq(&self, other: &Self) -> bool {
+        (self.a == other.a && self.b == other.b) || (self.a == other.b && self.b == other.a)
+    }
+}
+
+impl Eq for Pair {}
+
+impl Hash for Pair {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.a.hash(state);
+        self.b.hash(state);
+    }
+}
+
+
+#[derive(Debug, Clone)]
+struct Circuit {
+    points: HashSet<(i64, i64, i64)>,
+}
+
+impl Circuit {
+    fn new() -> Self {
+        Circuit {
+            points: HashSet::new(),
+        }
+    }
+
+    fn from(point: (i64, i64, i64)) -> Self {
+        let mut circuit = Circuit::new();
+        circuit.add_point(point);
+        circuit
+    }
+
+    fn add_point(&mut self, point: (i64, i64, i64)) {
+        self.points.insert(point);
+    }
+
+    fn union(&self, other: &Circuit) -> Circuit {
+        let mut new_circuit = self.clone();
+        new_circuit.points = new_circuit.points.union(&other.points).cloned().collect();
+        new_circuit
+    }
+
+    fn contains(&self, point: &(i64, i64, i64)) -> bool {
+        self.points.contains(point)
+    }
+}
+
+impl Ord for Circuit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.points.len().cmp(&other.points.len())
+    }
+}
+
+impl PartialOrd for Circuit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Circuit {
+    fn eq(&self, other: &Self) -> bool {
+        self.points.clone().symmetric_difference(&other.points.clone()).count() == 0
+    }
+}
+
+impl Eq for Circuit {}
+
+impl Hash for Circuit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let cloned_points: HashSet<(i64, i64, i64)> = self.points.clone();
+        let mut points_vec: Vec<(i64, i64, i64)> = cloned_points.into_iter().collect();
+        points_vec.sort();
+        for point in points_vec {
+            point.hash(state);
+        }
+    }
+}
+
+
+fn junction_box(line: Line) -> Result<(i64, i64, i64), ParseError> {
+    let (x_str, rest) = line.split_once(line.text, ',')?;
+    let (y_str, z_str) = line.split_once(rest, ',')?;
+    Ok((
+        line.parse::<i64>(x_str, "an X coordinate")?,
+        line.parse::<i64>(y_str, "a Y coordinate")?,
+        line.parse::<i64>(z_str, "a Z coordinate")?,
+    ))
+}
+
+/// How many of the closest pairs part 1 connects, and how many of the largest circuits it multiplies.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Connections {
+    /// Left out to connect 10 pairs for the 20 junction box example and 1000 otherwise.
+    pub num_pairs: Option<usize>,
+    pub largest: usize,
+}
+
+impl Default for Connections {
+    fn default() -> Self {
+        Connections { num_pairs: None, largest: 3 }
+    }
+}
+
+impl Connections {
+    fn num_pairs(&self, contents: &str) -> usize {
+        self.num_pairs.unwrap_or(if contents.lines().count() == 20 { 10 } else { 1000 })
+    }
+}
+
+/// The junction boxes and every pair of them, closest first.
+pub struct Playground {
+    lights: HashSet<(i64, i64, i64)>,
+    pairs: Vec<Pair>,
+    /// How many of the closest pairs part 1 connects; the example only connects 10.
+    num_pairs: usize,
+    largest: usize,
+    /// Where to point when there aren't enough junction boxes.
+    end: Span,
+}
+
+fn playground(contents: &str, connections: &Connections) -> Result<Playground, ParseError> {
+    let num_pairs = connections.num_pairs(contents);
+    let lights: HashSet<(i64, i64, i64)> = parse::lines(contents)
+        .map(junction_box)
+        .collect::<Result<_, ParseError>>()?;
+
+    let mut pairs: Vec<Pair> = lights
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| lights.iter().skip(i + 1).map(move |b| (a, b)))
+        .map(|(a, b)| Pair::new(*a, *b))
+        .collect::<HashSet<Pair>>().into_iter().collect();
+    pairs.sort();
+
+    Ok(Playground { lights, pairs, num_pairs, largest: connections.largest, end: Span::end_of_input(contents) })
+}
+
+/// Index of each junction box's circuit.
+type CircuitIxs = HashMap<(i64, i64, i64), usize>;
+
+/// Every junction box in a circuit of its own.
+fn circuits(lights: &HashSet<(i64, i64, i64)>) -> (CircuitIxs, Vec<Circuit>) {
+    let mut point_ixs: HashMap<(i64, i64, i64), usize> = HashMap::new();
+    let circuits: Vec<Circuit> = Vec::from_iter(
+        lights.iter().enumerate().map(|(ix, point)| {
+            point_ixs.insert(*point, ix);
+            Circuit::from(*point)
+        })
+    );
+    (point_ixs, circuits)
+}
+
+/// Merges the circuits of the pair's two boxes, which must be different.
+fn connect(pair: &Pair, point_ixs: &mut CircuitIxs, circuits: &mut Vec<Circuit>) {
+    let new_circuit: Circuit = {
+        let mut points = [pair.a, pair.b];
+        points.sort_by(|a, b| point_ixs[b].cmp(&point_ixs[a]));
+        points.iter().fold(Circuit::new(), |acc, point| acc.union(&circuits.remove(point_ixs[point])))
+    };
+
+    *point_ixs = HashMap::from_iter(
+        point_ixs.iter().map(|(point, ix)| {
+            (*point,
+                if new_circuit.contains(point) {
+                    circuits.len()
+                } else {
+                    *ix - (*ix > point_ixs[&pair.a]) as usize - (*ix > point_ixs[&pair.b]) as usize
+                }
+            )
+        })
+    );
+
+    circuits.push(new_circuit);
+}
+
+/// Junction boxes that appear twice (and so only count once), and junction box counts
+/// that change how many pairs part 1 connects or leave it too few.
+fn lint(contents: &str) -> Vec<ParseError> {
+    let mut problems: Vec<ParseError> = Vec::new();
+    let mut seen: HashMap<(i64, i64, i64), usize> = HashMap::new();
+    for line in parse::lines(contents) {
+        let Ok(point) = junction_box(line) else {
+            continue;
+        };
+        match seen.get(&point) {
+            Some(first) => problems.push(line.error(line.text, format!("a junction box not already on line {}", first))),
+            None => {
+                seen.insert(point, line.number);
+            }
+        }
+    }
+
+    let num_pairs = Connections::default().num_pairs(contents);
+    if contents.lines().count() == 20 {
+        problems.push(ParseError::end_of_input(contents, "other than 20 junction boxes, which is taken for the example and only connects 10 pairs"));
+    }
+    if seen.len() * seen.len().saturating_sub(1) / 2 < num_pairs {
+        problems.push(ParseError::end_of_input(contents, format!("enough junction boxes for {} pairs", num_pairs)));
+    }
+    problems
+}
+
+fn part1(playground: &Playground) -> Result<Int, Overflow> {
+    let (mut point_ixs, mut circuits) = circuits(&playground.lights);
+    
+    for pair in playground.pairs.iter().take(playground.num_pairs) {
+        if point_ixs[&pair.a] == point_ixs[&pair.b] {
+            continue;
+        }
+        connect(pair, &mut point_ixs, &mut circuits);
+    }
+
+    circuits.sort_by(|a, b| b.cmp(a));
+    return num::product(circuits.into_iter().take(playground.largest).map(|c| int(c.points.len() as i64)));
+}
+
+fn part2(playground: &Playground) -> Result<Int, SolveError> {
+    let (mut point_ixs, mut circuits) = circuits(&playground.lights);
+    
+    for pair in &playground.pairs {
+        if point_ixs[&pair.a] == point_ixs[&pair.b] {
+            continue;
+        }
+
+        if circuits.len() == 2 {
+            // This connection will fully connect, no need to actually do it
+            return Ok(num::mul(&int(pair.a.0), &int(pair.b.0))?);
+        }
+
+        connect(pair, &mut point_ixs, &mut circuits);
+    }
+    return Err(playground.end.error("a second junction box to connect").into());
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Playground";
+    const LABELS: [&'static str; 2] = [
+        "Product of 3 largest circuits",
+        "Products of x-coordinates of final connections",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../fixtures/example.txt"),
+            part1: Some("40"),
+            part2: Some("25272"),
+        },
+    ];
+    const RECORDS: Option<Records> = Some(Records::Lines);
+
+    type Input = Playground;
+    type Part1 = Int;
+    type Part2 = Int;
+    type Params = Connections;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        playground(contents, &Connections::default())
+    }
+
+    fn parse_with(contents: &str, connections: &Self::Params) -> Result<Self::Input, ParseError> {
+        playground(contents, connections)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        part2(input)
+    }
+
+    fn lint(contents: &str) -> Vec<ParseError> {
+        lint(contents)
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day8>(1);
+    }
+
+    #[test]
+    fn p2_test() {
+        aoc2025::examples::check::<Day8>(2);
+    }
+
+    #[test]
+    fn far_apart_boxes() {
+        let playground = playground("9223372036854775807,0,0\n-9223372036854775807,0,0\n0,0,0\n", &Connections::default()).unwrap();
+        let nearest = playground.pairs.iter().min().unwrap();
+        assert_eq!(nearest.dist, 9223372036854775807.0);
+    }
+
+    #[test]
+    fn lint_test() {
+        let problems = Day8::lint("1,2,3\n4,5,6\n1,2,3\n");
+        let found: Vec<(usize, &str)> = problems.iter().map(|err| (err.line, err.expected.as_str())).collect();
+        assert_eq!(found, vec![(3, "a junction box not already on line 1"), (4, "enough junction boxes for 1000 pairs")]);
+        assert_eq!(Day8::lint(Day8::EXAMPLES[0].input).len(), 1);
+    }
+}
@@ -0,0 +1,61 @@
+use aoc2025::num::int;
+use aoc2025::Int;
+
+fn boxes(contents: &str) -> Vec<Vec<i64>> {
+    contents.lines().map(|line| line.split(',').map(|n| n.parse().unwrap()).collect()).collect()
+}
+
+/// Every pair of boxes, closest first.
+fn pairs(boxes: &[Vec<i64>]) -> Vec<(usize, usize)> {
+    let mut pairs: Vec<(i64, usize, usize)> = Vec::new();
+    for i in 0..boxes.len() {
+        for j in (i + 1)..boxes.len() {
+            let dist = (0..3).map(|axis| (boxes[i][axis] - boxes[j][axis]).pow(2)).sum();
+            pairs.push((dist, i, j));
+        }
+    }
+    pairs.sort();
+    pairs.into_iter().map(|(_, i, j)| (i, j)).collect()
+}
+
+/// Relabels every box in `b`'s circuit with `a`'s circuit.
+fn connect(circuit: &mut [usize], a: usize, b: usize) {
+    let (keep, replace) = (circuit[a], circuit[b]);
+    for label in circuit.iter_mut() {
+        if *label == replace {
+            *label = keep;
+        }
+    }
+}
+
+/// Connects the closest pairs one at a time, tracking each box's circuit by label.
+pub fn part1(contents: &str) -> Int {
+    let boxes = boxes(contents);
+    let connections = if boxes.len() == 20 { 10 } else { 1000 };
+
+    let mut circuit: Vec<usize> = (0..boxes.len()).collect();
+    for (i, j) in pairs(&boxes).into_iter().take(connections) {
+        connect(&mut circuit, i, j);
+    }
+
+    let mut sizes: Vec<i64> = (0..boxes.len())
+        .map(|label| circuit.iter().filter(|&&c| c == label).count() as i64)
+        .filter(|&size| size > 0)
+        .collect();
+    sizes.sort_by(|a, b| b.cmp(a));
+    sizes.iter().take(3).map(|&size| int(size)).product()
+}
+
+/// Connects the closest pairs until every box is in one circuit.
+pub fn part2(contents: &str) -> Int {
+    let boxes = boxes(contents);
+
+    let mut circuit: Vec<usize> = (0..boxes.len()).collect();
+    for (i, j) in pairs(&boxes) {
+        connect(&mut circuit, i, j);
+        if circuit.iter().all(|&c| c == circuit[0]) {
+            return int(boxes[i][0]) * int(boxes[j][0]);
+        }
+    }
+    panic!("Boxes never all connect");
+}
@@ -0,0 +1,46 @@
+use aoc2025::Rng;
+use std::collections::BTreeSet;
+
+/// Size of a generated input.
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// Steps in the polygon's bottom edge
+    pub columns: usize,
+    pub extent: i64,
+}
+
+impl Params {
+    /// Small sizes also get a small extent, so corners often end up next to each other.
+    pub fn sized(size: usize) -> Self {
+        Params { columns: size.max(1), extent: (size.max(1) as i64 * 10).min(100_000) }
+    }
+}
+
+fn distinct(rng: &mut Rng, count: usize, range: std::ops::RangeInclusive<i64>) -> Vec<i64> {
+    let mut values: BTreeSet<i64> = BTreeSet::new();
+    while values.len() < count {
+        values.insert(rng.int(range.clone()));
+    }
+    values.into_iter().collect()
+}
+
+/// The red tiles at the corners of a rectilinear polygon, one `x,y` per line in order
+/// around it. The polygon hangs from a flat top edge, with a different depth for each column.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    assert!(params.extent > params.columns as i64, "Extent too small for that many columns");
+
+    let xs = distinct(rng, params.columns + 1, 0..=params.extent);
+    let mut depths = distinct(rng, params.columns, 1..=params.extent);
+    rng.shuffle(&mut depths);
+
+    let mut corners: Vec<(i64, i64)> = vec![(xs[0], 0), (xs[params.columns], 0)];
+    for column in (0..params.columns).rev() {
+        corners.push((xs[column + 1], depths[column]));
+        corners.push((xs[column], depths[column]));
+    }
+
+    // Start somewhere other than the top-left corner
+    let start = rng.index(corners.len());
+    corners.rotate_left(start);
+    corners.iter().map(|(x, y)| format!("{},{}\n", x, y)).collect()
+}
@@ -0,0 +1,265 @@
+use aoc2025::params::NoParams;
+use aoc2025::parse::{self, Line};
+use aoc2025::num::{self, int};
+use aoc2025::{Example, Int, Overflow, ParseError, Records, Rng, SolveError, Solution};
+use std::collections::*;
+use itertools::Itertools;
+
+pub mod generate;
+pub mod reference;
+
+
+fn corner(line: Line) -> Result<(i64, i64), ParseError> {
+    let (x_str, y_str) = line.split_once(line.text, ',')?;
+    Ok((
+        line.parse::<i64>(x_str, "an X coordinate")?,
+        line.parse::<i64>(y_str, "a Y coordinate")?,
+    ))
+}
+
+fn corners(contents: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let mut corners: Vec<(i64, i64)> = Vec::new();
+    for line in parse::lines(contents) {
+        let (x, y) = corner(line)?;
+        if corners.last().is_some_and(|&(px, py)| px != x && py != y) {
+            return Err(line.error(line.text, "a red tile in the same row or column as the previous one"));
+        }
+        corners.push((x, y));
+    }
+
+    let (Some(&(fx, fy)), Some(&(lx, ly))) = (corners.first(), corners.last()) else {
+        return Err(ParseError::end_of_input(contents, "a red tile"));
+    };
+    if fx != lx && fy != ly {
+        return Err(ParseError::end_of_input(contents, "a red tile in the same row or column as the first one"));
+    }
+    Ok(corners)
+}
+
+/// Tiles in the rectangle with opposite corners `a` and `b`.
+fn area((x1, y1): (i64, i64), (x2, y2): (i64, i64)) -> Result<Int, Overflow> {
+    let side = |a: i64, b: i64| num::add(&num::sub(&int(a.max(b)), &int(a.min(b)))?, &int(1));
+    num::mul(&side(x1, x2)?, &side(y1, y2)?)
+}
+
+/// Every edge, including the one closing the loop, that isn't along a row or column.
+/// `corners` stops at the first, and part 2 would skip them.
+fn lint(contents: &str) -> Vec<ParseError> {
+    let corners: Vec<(Line, (i64, i64))> = parse::lines(contents)
+        .filter_map(|line| corner(line).ok().map(|corner| (line, corner)))
+        .collect();
+
+    let mut problems: Vec<ParseError> = corners.windows(2)
+        .filter(|pair| pair[0].1.0 != pair[1].1.0 && pair[0].1.1 != pair[1].1.1)
+        .map(|pair| pair[1].0.error(pair[1].0.text, format!("a red tile in the same row or column as line {}", pair[0].0.number)))
+        .collect();
+    if let (Some((first, (fx, fy))), Some((_, (lx, ly)))) = (corners.first(), corners.last()) {
+        if fx != lx && fy != ly {
+            problems.push(ParseError::end_of_input(contents, format!("a last red tile in the same row or column as line {}", first.number)));
+        }
+    }
+    problems
+}
+
+fn part1(corners: &[(i64, i64)]) -> Result<Int, Overflow> {
+    return corners.iter().cartesian_product(corners.iter())
+        .try_fold(int(0), |best, (a, b)| Ok(best.max(area(*a, *b)?)));
+}
+
+/// Index of each distinct coordinate once sorted, leaving a gap wherever there are tiles
+/// between two coordinates so a notch between them can't be skipped over.
+fn reduced(mut values: Vec<i64>) -> HashMap<i64, i64> {
+    values.sort();
+    values.dedup();
+
+    let mut index = 0;
+    let mut indices: HashMap<i64, i64> = HashMap::new();
+    for (ix, value) in values.iter().enumerate() {
+        if ix > 0 {
+            index += if value.abs_diff(values[ix - 1]) > 1 { 2 } else { 1 };
+        }
+        indices.insert(*value, index);
+    }
+    return indices;
+}
+
+fn part2(corners: &[(i64, i64)]) -> Result<Int, Overflow> {
+    let x_index: HashMap<i64, i64> = reduced(corners.iter().map(|(x, _)| *x).collect());
+    let y_index: HashMap<i64, i64> = reduced(corners.iter().map(|(_, y)| *y).collect());
+
+    let reduced_corners: Vec<(i64, i64)> = corners.iter()
+        .map(|(x, y)| (x_index[x], y_index[y]))
+        .collect();
+
+    let shape: HashSet<(i64, i64)> = HashSet::from_iter(
+        Itertools::zip_eq(reduced_corners.iter(), reduced_corners.iter().skip(1).chain(std::iter::once(&reduced_corners[0])))
+            .flat_map(|((x1, y1), (x2, y2))| {
+                let mut points: Vec<(i64, i64)> = Vec::new();
+
+                if x1 == x2 {
+                    let (start, end) = if y1 < y2 { (*y1, *y2) } else { (*y2, *y1) };
+                    for y in start..=end {
+                        points.push((*x1, y));
+                    }
+                } else if y1 == y2 {
+                    let (start, end) = if x1 < x2 { (*x1, *x2) } else { (*x2, *x1) };
+                    for x in start..=end {
+                        points.push((x, *y1));
+                    }
+                }
+
+                points.into_iter()
+            })
+    );
+
+    // Flood-fill the outside from a margin around the shape, so whatever isn't reached is inside
+    let max_x = *x_index.values().max().unwrap() + 1;
+    let max_y = *y_index.values().max().unwrap() + 1;
+    let mut outside: HashSet<(i64, i64)> = HashSet::new();
+    let mut to_visit: VecDeque<(i64, i64)> = VecDeque::from([(-1, -1)]);
+
+    while let Some((x, y)) = to_visit.pop_front() {
+        if outside.contains(&(x, y)) {
+            continue;
+        }
+
+        outside.insert((x, y));
+
+        let neighbors = vec![
+            (x + 1, y),
+            (x - 1, y),
+            (x, y + 1),
+            (x, y - 1),
+        ];
+
+        for (nx, ny) in neighbors {
+            if (-1..=max_x).contains(&nx) && (-1..=max_y).contains(&ny) && !shape.contains(&(nx, ny)) && !outside.contains(&(nx, ny)) {
+                to_visit.push_back((nx, ny));
+            }
+        }
+    }
+
+    return corners.iter().cartesian_product(corners.iter())
+        .filter(|((x1, y1), (x2, y2))| {
+            let rx1 = x_index[x1];
+            let ry1 = y_index[y1];
+            let rx2 = x_index[x2];
+            let ry2 = y_index[y2];
+            let corners = [(rx1, ry1), (rx1, ry2), (rx2, ry2), (rx2, ry1)];
+            // Check if all boundary points are in the shape
+            let mut all_in_shape = true;
+            for (c1, c2) in Itertools::zip_eq(corners.iter(), corners.iter().skip(1).chain(std::iter::once(&corners[0]))) {
+                if c1.0 == c2.0 {
+                    let (start, end) = if c1.1 < c2.1 { (c1.1, c2.1) } else { (c2.1, c1.1) };
+                    for y in start..=end {
+                        if outside.contains(&(c1.0, y)) {
+                            all_in_shape = false;
+                            break;
+                        }
+                    }
+                } else if c1.1 == c2.1 {
+                    let (start, end) = if c1.0 < c2.0 { (c1.0, c2.0) } else { (c2.0, c1.0) };
+                    for x in start..=end {
+                        if outside.contains(&(x, c1.1)) {
+                            all_in_shape = false;
+                            break;
+                        }
+                    }
+                }
+                if !all_in_shape {
+                    break;
+                }
+            }
+            all_in_shape
+        })
+        .try_fold(int(0), |best, (a, b)| Ok(best.max(area(*a, *b)?)));
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Movie Theater";
+    const LABELS: [&'static str; 2] = [
+        "Largest rectangle",
+        "Largest fully contained rectangle",
+    ];
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            name: "example",
+            input: include_str!("../fixtures/example.txt"),
+            part1: Some("50"),
+            part2: Some("24"),
+        },
+    ];
+    const RECORDS: Option<Records> = Some(Records::Lines);
+
+    /// The red tiles, in order around the loop.
+    type Input = Vec<(i64, i64)>;
+    type Part1 = Int;
+    type Part2 = Int;
+    type Params = NoParams;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError> {
+        corners(contents)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Ok(part2(input)?)
+    }
+
+    fn lint(contents: &str) -> Vec<ParseError> {
+        lint(contents)
+    }
+
+    fn reference1(contents: &str) -> Self::Part1 {
+        reference::part1(contents)
+    }
+
+    fn reference2(contents: &str) -> Self::Part2 {
+        reference::part2(contents)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate::generate(rng, &generate::Params::sized(size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn p1_test() {
+        aoc2025::examples::check::<Day9>(1);
+    }
+
+    #[test]
+    fn p2_test() {
+        aoc2025::examples::check::<Day9>(2);
+    }
+
+    #[test]
+    fn far_apart_corners() {
+        let contents = "-9223372036854775807,0\n9223372036854775807,0\n9223372036854775807,1\n-9223372036854775807,1\n";
+        let solved = aoc2025::Puzzle::of::<Day9>().solve(contents, &[2]);
+
+        if aoc2025::num::INT_NAME == "i64" {
+            assert!(matches!(solved, Err(SolveError::Overflow(_))));
+        } else {
+            assert_eq!(solved.unwrap().parts[0].answer.to_string(), "36893488147419103230");
+        }
+    }
+
+    #[test]
+    fn lint_test() {
+        let problems = Day9::lint("1,1\n5,1\n6,4\n6,9\n2,8\n");
+        let found: Vec<usize> = problems.iter().map(|err| err.line).collect();
+        assert_eq!(found, vec![3, 5, 6]);
+        assert!(Day9::lint(Day9::EXAMPLES[0].input).is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+use aoc2025::num::int;
+use aoc2025::Int;
+
+fn corners(contents: &str) -> Vec<(i64, i64)> {
+    contents.lines()
+        .map(|line| {
+            let (x, y) = line.split_once(',').unwrap();
+            (x.parse().unwrap(), y.parse().unwrap())
+        })
+        .collect()
+}
+
+fn area((x1, y1): (i64, i64), (x2, y2): (i64, i64)) -> Int {
+    int((x1 - x2).abs() + 1) * int((y1 - y2).abs() + 1)
+}
+
+/// Tries every pair of corners.
+pub fn part1(contents: &str) -> Int {
+    let corners = corners(contents);
+    let mut best = int(0);
+    for &a in &corners {
+        for &b in &corners {
+            best = best.max(area(a, b));
+        }
+    }
+    return best;
+}
+
+/// Whether tile `(x, y)` is red or green: on an edge of the loop, or inside it by ray casting.
+fn colored(corners: &[(i64, i64)], (x, y): (i64, i64)) -> bool {
+    let edges = corners.iter().zip(corners.iter().cycle().skip(1));
+
+    let mut crossings = 0;
+    for (&(x1, y1), &(x2, y2)) in edges {
+        if x1.min(x2) <= x && x <= x1.max(x2) && y1.min(y2) <= y && y <= y1.max(y2) {
+            return true;
+        }
+        if x1 == x2 && x1 > x && y1.min(y2) <= y && y < y1.max(y2) {
+            crossings += 1;
+        }
+    }
+    return crossings % 2 == 1;
+}
+
+/// Coordinates worth checking along one axis: every corner's, and one between each pair
+/// of neighbouring corners, since nothing changes between them.
+fn samples(values: impl Iterator<Item = i64>) -> Vec<i64> {
+    let mut values: Vec<i64> = values.collect();
+    values.sort();
+    values.dedup();
+
+    let mut samples = Vec::new();
+    for pair in values.windows(2) {
+        samples.push(pair[0]);
+        if pair[1] - pair[0] > 1 {
+            samples.push(pair[0] + 1);
+        }
+    }
+    samples.push(*values.last().unwrap());
+    return samples;
+}
+
+/// Tries every pair of corners, checking a tile from every distinct part of the rectangle.
+pub fn part2(contents: &str) -> Int {
+    let corners = corners(contents);
+    let xs = samples(corners.iter().map(|&(x, _)| x));
+    let ys = samples(corners.iter().map(|&(_, y)| y));
+
+    let mut best = int(0);
+    for &a in &corners {
+        for &b in &corners {
+            if area(a, b) <= best {
+                continue;
+            }
+
+            let inside = xs.iter()
+                .filter(|&&x| a.0.min(b.0) <= x && x <= a.0.max(b.0))
+                .all(|&x| {
+                    ys.iter()
+                        .filter(|&&y| a.1.min(b.1) <= y && y <= a.1.max(b.1))
+                        .all(|&y| colored(&corners, (x, y)))
+                });
+            if inside {
+                best = area(a, b);
+            }
+        }
+    }
+    return best;
+}
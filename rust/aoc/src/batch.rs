@@ -0,0 +1,362 @@
+use crate::output::{AnswerRecord, Format};
+use crate::table::print_table;
+use aoc::puzzle;
+use aoc2025::{Answer, YEAR};
+use clap::Args;
+use serde::Serialize;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, ExitStatus, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// How often to check whether a part has finished.
+const POLL: Duration = Duration::from_millis(10);
+
+#[derive(Args)]
+pub struct BatchArgs {
+    /// Directory to search for inputs named like 2025_7.txt or 2025_7_alt-account.txt, e.g. one folder per account
+    dir: PathBuf,
+
+    /// Seconds each part may run before it's stopped
+    #[arg(short, long, default_value_t = 60)]
+    timeout: u64,
+
+    /// How to print the results
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+/// An input found under the batch directory. `set` is its directory relative to the
+/// batch directory, plus its name if it has one, so each account gets a column.
+struct BatchInput {
+    set: String,
+    day: u32,
+    path: PathBuf,
+}
+
+/// The day and name of an input file named the way `InputResolver` looks them up.
+fn input_day(file_name: &str) -> Option<(u32, Option<&str>)> {
+    let rest = file_name.strip_suffix(".txt")?.strip_prefix(format!("{}_", YEAR).as_str())?;
+    let (day, name) = match rest.split_once('_') {
+        Some((day, name)) => (day, Some(name)),
+        None => (rest, None),
+    };
+    let day = day.parse().ok().filter(|&day| puzzle(day).is_some())?;
+    Some((day, name))
+}
+
+fn find_inputs(root: &Path, dir: &Path, inputs: &mut Vec<BatchInput>) -> io::Result<()> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)?.map(|entry| entry.map(|entry| entry.path())).collect::<io::Result<_>>()?;
+    paths.sort();
+
+    for path in paths {
+        if path.is_dir() {
+            find_inputs(root, &path, inputs)?;
+            continue;
+        }
+        let Some((day, name)) = path.file_name().and_then(|name| name.to_str()).and_then(input_day) else {
+            continue;
+        };
+
+        let dir = dir.strip_prefix(root).unwrap_or(dir).display().to_string();
+        let dir = if dir.is_empty() { ".".to_string() } else { dir };
+        let set = match name {
+            Some(name) => format!("{} [{}]", dir, name),
+            None => dir,
+        };
+        inputs.push(BatchInput { set, day, path });
+    }
+    Ok(())
+}
+
+/// How a part went in its own process.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "kebab-case")]
+enum Outcome {
+    Answer { answer: Answer, elapsed_ns: u128 },
+    Error { message: String },
+    /// Panicked, or was killed by a signal such as a stack overflow's abort.
+    Panic { message: String },
+    Timeout,
+}
+
+/// One cell of the matrix, as emitted by `--format json` and `--format tsv`.
+#[derive(Debug, Clone, Serialize)]
+struct BatchRecord {
+    set: String,
+    input: String,
+    day: u32,
+    part: u8,
+    #[serde(flatten)]
+    outcome: Outcome,
+    /// Time the whole process took, including starting up and parsing.
+    wall_ns: u128,
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = String::new();
+        let _ = pipe.read_to_string(&mut text);
+        text
+    })
+}
+
+/// The panic message and where it panicked from a crashed run's stderr, or its last line if
+/// there's no panic, like after a stack overflow.
+fn panic_message(stderr: &str) -> String {
+    let lines: Vec<&str> = stderr.lines().collect();
+    let Some(ix) = lines.iter().position(|line| line.contains(" panicked at ")) else {
+        return lines.iter().rev().find(|line| !line.trim().is_empty()).unwrap_or(&"no output").to_string();
+    };
+
+    let location = lines[ix].split_once(" panicked at ").map_or("", |(_, at)| at.trim_end_matches(':'));
+    let message: Vec<&str> = lines[ix + 1..]
+        .iter()
+        .take_while(|line| !line.starts_with("note:") && !line.starts_with("stack backtrace:"))
+        .copied()
+        .collect();
+    format!("{} ({})", message.join(" "), location)
+}
+
+fn outcome(status: ExitStatus, stdout: &str, stderr: &str) -> Outcome {
+    if status.success() {
+        return match serde_json::from_str::<Vec<AnswerRecord>>(stdout) {
+            Ok(mut records) if records.len() == 1 => {
+                let record = records.remove(0);
+                Outcome::Answer { answer: record.answer, elapsed_ns: record.elapsed_ns }
+            }
+            _ => Outcome::Error { message: "couldn't read the answer from `aoc run`".to_string() },
+        };
+    }
+
+    // Rust exits with 101 after a panic; no code at all means a signal killed it
+    match status.code() {
+        Some(101) | None => Outcome::Panic { message: panic_message(stderr) },
+        Some(_) => Outcome::Error {
+            message: stderr.lines().find_map(|line| line.strip_prefix("error: ")).unwrap_or(stderr.trim()).to_string(),
+        },
+    }
+}
+
+/// Runs `part` of `day` on `input` in a child `aoc run`, so a panic, abort or hang only
+/// takes that part down. Returns how it went and how long the child ran.
+fn run_part(input: &Path, day: u32, part: u8, timeout: Duration) -> (Outcome, Duration) {
+    let start = Instant::now();
+    let spawned = env::current_exe().and_then(|exe| {
+        Command::new(exe)
+            .args(["run", &day.to_string(), "--part", &part.to_string(), "--format", "json", "--input"])
+            .arg(input)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+    });
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(err) => return (Outcome::Error { message: format!("couldn't start `aoc run`: {}", err) }, start.elapsed()),
+    };
+
+    // Drain the pipes while waiting, so a chatty child can't block on a full one
+    let stdout = read_in_background(child.stdout.take().unwrap());
+    let stderr = read_in_background(child.stderr.take().unwrap());
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if start.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+            Ok(None) => thread::sleep(POLL),
+            Err(err) => return (Outcome::Error { message: err.to_string() }, start.elapsed()),
+        }
+    };
+    let elapsed = start.elapsed();
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    match status {
+        Some(status) => (outcome(status, &stdout, &stderr), elapsed),
+        None => (Outcome::Timeout, elapsed),
+    }
+}
+
+fn cell(outcome: &Outcome, timeout: u64) -> String {
+    match outcome {
+        Outcome::Answer { answer, elapsed_ns } => {
+            format!("{} ({:.2?})", answer.to_string().replace('\n', " / "), Duration::from_nanos(*elapsed_ns as u64))
+        }
+        Outcome::Error { .. } => "ERROR".to_string(),
+        Outcome::Panic { .. } => "PANIC".to_string(),
+        Outcome::Timeout => format!("TIMEOUT (>{}s)", timeout),
+    }
+}
+
+/// A row per day and part, a column per input set, and what went wrong below.
+fn print_matrix(records: &[BatchRecord], timeout: u64) {
+    let mut sets: Vec<&str> = Vec::new();
+    let mut rows: Vec<(u32, u8)> = Vec::new();
+    for record in records {
+        if !sets.contains(&record.set.as_str()) {
+            sets.push(&record.set);
+        }
+        if !rows.contains(&(record.day, record.part)) {
+            rows.push((record.day, record.part));
+        }
+    }
+    rows.sort();
+
+    let table: Vec<Vec<String>> = rows
+        .iter()
+        .map(|&(day, part)| {
+            let cells = sets.iter().map(|set| {
+                records
+                    .iter()
+                    .find(|record| (record.day, record.part, record.set.as_str()) == (day, part, *set))
+                    .map_or("-".to_string(), |record| cell(&record.outcome, timeout))
+            });
+            [day.to_string(), part.to_string()].into_iter().chain(cells).collect()
+        })
+        .collect();
+    let header: Vec<&str> = ["Day", "Part"].into_iter().chain(sets.iter().copied()).collect();
+    print_table(&header, &table);
+
+    let failures: Vec<&BatchRecord> = records.iter().filter(|record| !matches!(record.outcome, Outcome::Answer { .. })).collect();
+    if !failures.is_empty() {
+        println!();
+    }
+    for record in &failures {
+        let what = match &record.outcome {
+            Outcome::Error { message } => format!("error: {}", message),
+            Outcome::Panic { message } => format!("panicked: {}", message),
+            Outcome::Timeout => format!("stopped after {}s", timeout),
+            Outcome::Answer { .. } => unreachable!(),
+        };
+        println!("Day {} part {} on {}: {}", record.day, record.part, record.input, what);
+    }
+
+    let answered = records.len() - failures.len();
+    let wall: Duration = records.iter().map(|record| Duration::from_nanos(record.wall_ns as u64)).sum();
+    println!("\n{} of {} parts answered across {} inputs in {:.2?}", answered, records.len(), sets.len(), wall);
+}
+
+fn print_records(format: Format, records: &[BatchRecord]) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", serde_json::to_string_pretty(records).unwrap()),
+        Format::Tsv => {
+            println!("set\tinput\tday\tpart\tstatus\tresult\telapsed_ns\twall_ns");
+            for record in records {
+                let (status, result, elapsed) = match &record.outcome {
+                    Outcome::Answer { answer, elapsed_ns } => ("answer", answer.to_string(), elapsed_ns.to_string()),
+                    Outcome::Error { message } => ("error", message.clone(), String::new()),
+                    Outcome::Panic { message } => ("panic", message.clone(), String::new()),
+                    Outcome::Timeout => ("timeout", String::new(), String::new()),
+                };
+                let fields = [record.set.clone(), record.input.clone(), record.day.to_string(), record.part.to_string(), status.to_string(), result, elapsed, record.wall_ns.to_string()];
+                println!("{}", fields.map(|field| field.replace(['\t', '\n', '\r'], " ")).join("\t"));
+            }
+        }
+    }
+}
+
+pub fn batch(args: &BatchArgs) -> ExitCode {
+    let mut inputs: Vec<BatchInput> = Vec::new();
+    if let Err(err) = find_inputs(&args.dir, &args.dir, &mut inputs) {
+        eprintln!("error: Could not search {}: {}", args.dir.display(), err);
+        return ExitCode::FAILURE;
+    }
+    if inputs.is_empty() {
+        eprintln!("error: No inputs named like {}_<day>.txt under {}", YEAR, args.dir.display());
+        return ExitCode::FAILURE;
+    }
+
+    let timeout = Duration::from_secs(args.timeout);
+    let mut records: Vec<BatchRecord> = Vec::new();
+    for input in &inputs {
+        for part in [1, 2] {
+            eprintln!("[{}/{}] Day {} part {} on {}", records.len() + 1, inputs.len() * 2, input.day, part, input.path.display());
+            let (outcome, wall) = run_part(&input.path, input.day, part, timeout);
+            records.push(BatchRecord {
+                set: input.set.clone(),
+                input: input.path.display().to_string(),
+                day: input.day,
+                part,
+                outcome,
+                wall_ns: wall.as_nanos(),
+            });
+        }
+    }
+
+    match args.format {
+        Format::Text => print_matrix(&records, args.timeout),
+        format => print_records(format, &records),
+    }
+
+    if records.iter().all(|record| matches!(record.outcome, Outcome::Answer { .. })) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::process::ExitStatusExt;
+
+    const PANIC: &str = "thread 'main' (4242) panicked at 10/src/lib.rs:88:21:\nattempt to multiply with overflow\nnote: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n";
+    const ANSWER: &str = r#"[{"year":2025,"day":1,"part":1,"label":"Password","answer":3,"elapsed_ns":1500,"parse_ns":900,"input":"2025_1.txt","input_sha256":"ab"}]"#;
+
+    /// How a process that exited with `code` looks to `wait`.
+    fn exited(code: i32) -> ExitStatus {
+        ExitStatus::from_raw(code << 8)
+    }
+
+    fn killed(signal: i32) -> ExitStatus {
+        ExitStatus::from_raw(signal)
+    }
+
+    #[test]
+    fn input_day_test() {
+        assert_eq!(input_day("2025_7.txt"), Some((7, None)));
+        assert_eq!(input_day("2025_12_alt-account.txt"), Some((12, Some("alt-account"))));
+        assert_eq!(input_day("2025_13.txt"), None);
+        assert_eq!(input_day("2024_7.txt"), None);
+        assert_eq!(input_day("2025_7.in"), None);
+        assert_eq!(input_day("2025_x.txt"), None);
+    }
+
+    #[test]
+    fn panic_message_test() {
+        assert_eq!(panic_message(PANIC), "attempt to multiply with overflow (10/src/lib.rs:88:21)");
+        assert_eq!(
+            panic_message("thread 'main' panicked at 4/src/lib.rs:3:5:\nfirst line\nsecond line\nstack backtrace:\n   0: main\n"),
+            "first line second line (4/src/lib.rs:3:5)"
+        );
+        assert_eq!(panic_message("\nthread 'main' has overflowed its stack\nfatal runtime error: stack overflow\n\n"), "fatal runtime error: stack overflow");
+        assert_eq!(panic_message(""), "no output");
+    }
+
+    #[test]
+    fn outcome_test() {
+        let Outcome::Answer { answer, elapsed_ns } = outcome(exited(0), ANSWER, "") else { panic!("a clean exit isn't an answer") };
+        assert_eq!((answer.to_string(), elapsed_ns), ("3".to_string(), 1500));
+        assert!(matches!(outcome(exited(0), "Day 1 part 1: 3", ""), Outcome::Error { .. }));
+
+        let Outcome::Panic { message } = outcome(exited(101), "", PANIC) else { panic!("exit code 101 isn't a panic") };
+        assert_eq!(message, "attempt to multiply with overflow (10/src/lib.rs:88:21)");
+        let Outcome::Panic { message } = outcome(killed(6), "", "fatal runtime error: stack overflow\n") else { panic!("SIGABRT isn't a panic") };
+        assert_eq!(message, "fatal runtime error: stack overflow");
+
+        let Outcome::Error { message } = outcome(exited(1), "", "warning: trailing spaces\nerror: Input 2025_1.txt, line 2: expected L or R\n") else {
+            panic!("exit code 1 isn't an error")
+        };
+        assert_eq!(message, "Input 2025_1.txt, line 2: expected L or R");
+        let Outcome::Error { message } = outcome(exited(2), "", "  usage: aoc run <DAY>\n") else { panic!("exit code 2 isn't an error") };
+        assert_eq!(message, "usage: aoc run <DAY>");
+    }
+}
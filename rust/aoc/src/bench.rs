@@ -0,0 +1,132 @@
+use crate::table::print_table;
+use crate::{DaySelection, InputArgs, ParamArgs};
+use aoc2025::bench::{BenchOptions, BenchResult};
+use aoc2025::params::ParamTable;
+use aoc2025::{Config, Input, InputResolver, Puzzle, YEAR};
+use clap::Args;
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, ExitCode};
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Untimed runs before measuring
+    #[arg(long, default_value_t = BenchOptions::default().warmup)]
+    warmup: usize,
+
+    /// Timed runs of each stage
+    #[arg(long, default_value_t = BenchOptions::default().iterations)]
+    iterations: usize,
+
+    /// Where to write the results as JSON
+    #[arg(short, long, default_value = "aoc-bench.json")]
+    output: PathBuf,
+
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+/// Contents of the results file.
+#[derive(Serialize)]
+struct BenchReport {
+    year: u32,
+    commit: Option<String>,
+    warmup: usize,
+    iterations: usize,
+    results: Vec<BenchRecord>,
+}
+
+#[derive(Serialize)]
+struct BenchRecord {
+    day: u32,
+    stage: String,
+    input: String,
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+    stddev_ns: u128,
+}
+
+impl BenchRecord {
+    fn new(result: &BenchResult, input: &str) -> Self {
+        BenchRecord {
+            day: result.day,
+            stage: result.stage.to_string(),
+            input: input.to_string(),
+            min_ns: result.stats.min.as_nanos(),
+            median_ns: result.stats.median.as_nanos(),
+            mean_ns: result.stats.mean.as_nanos(),
+            stddev_ns: result.stats.stddev.as_nanos(),
+        }
+    }
+}
+
+/// Current commit, so results from different revisions can be told apart.
+fn git_commit() -> Option<String> {
+    let output = Command::new("git").args(["rev-parse", "--short", "HEAD"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn bench_day(resolver: &InputResolver, puzzle: &Puzzle, input: &InputArgs, parts: &[u8], params: &ParamTable, options: &BenchOptions) -> Result<(Vec<BenchResult>, String), String> {
+    let Input { path, contents, .. } = input.read(resolver, puzzle)?;
+
+    let results = puzzle.bench(&contents, parts, params, options)
+        .map_err(|err| err.render(&path, &contents))?;
+    Ok((results, path))
+}
+
+pub fn bench(config: &Config, resolver: &InputResolver, selection: &DaySelection, input: &InputArgs, args: &BenchArgs) -> ExitCode {
+    let options = BenchOptions { warmup: args.warmup, iterations: args.iterations };
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut records: Vec<BenchRecord> = Vec::new();
+    let mut failed = false;
+    for puzzle in selection.puzzles() {
+        match bench_day(resolver, puzzle, input, &selection.parts(), &args.params.table(&config.params, puzzle.day), &options) {
+            Ok((results, path)) => {
+                for result in &results {
+                    let stats = &result.stats;
+                    rows.push(vec![
+                        result.day.to_string(),
+                        result.stage.to_string(),
+                        format!("{:.5?}", stats.min),
+                        format!("{:.5?}", stats.median),
+                        format!("{:.5?}", stats.mean),
+                        format!("{:.5?}", stats.stddev),
+                    ]);
+                    records.push(BenchRecord::new(result, &path));
+                }
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                failed = true;
+            }
+        }
+    }
+
+    print_table(&["Day", "Stage", "Min", "Median", "Mean", "Stddev"], &rows);
+
+    let report = BenchReport {
+        year: YEAR,
+        commit: git_commit(),
+        warmup: options.warmup,
+        iterations: options.iterations,
+        results: records,
+    };
+    let json = serde_json::to_string_pretty(&report).unwrap();
+    if let Err(err) = fs::write(&args.output, json + "\n") {
+        eprintln!("error: Could not write {}: {}", args.output.display(), err);
+        return ExitCode::FAILURE;
+    }
+    println!("\nWrote {}", args.output.display());
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,40 @@
+use crate::DaySelection;
+use aoc2025::differential;
+use clap::Args;
+use std::process::ExitCode;
+
+#[derive(Args)]
+pub struct DiffArgs {
+    /// First seed to generate inputs from
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+
+    /// How many inputs to try for each day
+    #[arg(short, long, default_value_t = 100)]
+    cases: u64,
+
+    /// Roughly how many items each input has; keep it small for the reference solvers
+    #[arg(long, default_value_t = 5)]
+    size: usize,
+}
+
+pub fn diff(selection: &DaySelection, args: &DiffArgs) -> ExitCode {
+    let mut failed = false;
+    for puzzle in selection.puzzles() {
+        match differential::check(puzzle, &selection.parts(), args.seed, args.cases, args.size) {
+            Ok(()) => println!("Day {}: {} inputs agree", puzzle.day, args.cases),
+            Err(disagreement) => {
+                eprintln!("{}", disagreement);
+                eprintln!("  reproduce with: aoc generate {} --seed {} --size {}", disagreement.day, disagreement.seed, disagreement.size);
+                eprintln!("  shrink with: aoc minimize {} --part {} --seed {} --size {}", disagreement.day, disagreement.part, disagreement.seed, disagreement.size);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
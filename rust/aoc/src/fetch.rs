@@ -0,0 +1,40 @@
+use aoc2025::client::Client;
+use aoc2025::input::{self, InputResolver};
+use aoc2025::Config;
+use clap::Args;
+use std::process::ExitCode;
+
+#[derive(Args)]
+pub struct FetchArgs {
+    /// Day to download the input for
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: u32,
+
+    /// Which of the day's inputs to save it as, e.g. 'alt-account'
+    #[arg(short, long, default_value = input::DEFAULT_NAME)]
+    name: String,
+
+    /// Download it again even if it's already there
+    #[arg(long)]
+    force: bool,
+}
+
+pub fn fetch(config: &Config, resolver: &InputResolver, args: &FetchArgs) -> ExitCode {
+    let downloaded = Client::from_env(&config.client)
+        .and_then(|client| client.download(resolver, args.day, &args.name, args.force));
+
+    match downloaded {
+        Ok((path, true)) => {
+            println!("Downloaded day {}'s input to {}", args.day, path.display());
+            ExitCode::SUCCESS
+        }
+        Ok((path, false)) => {
+            println!("Day {}'s input is already at {}", args.day, path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use aoc::puzzle;
+use clap::Args;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: u32,
+
+    /// Seed for the generator, random if not given
+    #[arg(short, long)]
+    seed: Option<u64>,
+
+    /// Roughly how many items (lines, ranges, machines...) to generate
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+}
+
+pub fn generate(args: &GenerateArgs) -> ExitCode {
+    let puzzle = puzzle(args.day).expect("Day should be validated by the argument parser");
+    let seed = args.seed.unwrap_or_else(|| {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64
+    });
+
+    // On stderr so the input can be piped straight into `aoc run -i -`
+    eprintln!("seed: {}", seed);
+    print!("{}", puzzle.generate(seed, args.size));
+    ExitCode::SUCCESS
+}
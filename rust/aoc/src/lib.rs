@@ -0,0 +1,59 @@
+use aoc2025::Puzzle;
+
+/// Every implemented day, in order.
+pub static PUZZLES: [Puzzle; 12] = [
+    Puzzle::of::<rust_2025_1::Day1>(),
+    Puzzle::of::<rust_2025_2::Day2>(),
+    Puzzle::of::<rust_2025_3::Day3>(),
+    Puzzle::of::<rust_2025_4::Day4>(),
+    Puzzle::of::<rust_2025_5::Day5>(),
+    Puzzle::of::<rust_2025_6::Day6>(),
+    Puzzle::of::<rust_2025_7::Day7>(),
+    Puzzle::of::<rust_2025_8::Day8>(),
+    Puzzle::of::<rust_2025_9::Day9>(),
+    Puzzle::of::<rust_2025_10::Day10>(),
+    Puzzle::of::<rust_2025_11::Day11>(),
+    Puzzle::of::<rust_2025_12::Day12>(),
+];
+
+pub fn puzzle(day: u32) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|puzzle| puzzle.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for puzzle in &PUZZLES {
+            for seed in 0..3 {
+                let contents = puzzle.generate(seed, 5);
+                if let Err(err) = puzzle.solve(&contents, &[1, 2]) {
+                    panic!("Seed {}:\n{}", seed, err.render("generated", &contents));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parsed_parts_match_solve() {
+        for puzzle in &PUZZLES {
+            let contents = puzzle.generate(0, 5);
+            let solved = puzzle.solve(&contents, &[1, 2]).unwrap();
+            let parsed = puzzle.parse(&contents, &Default::default()).unwrap();
+            for result in &solved.parts {
+                assert_eq!(parsed.part(result.part).unwrap().answer, result.answer, "Day {} part {}", puzzle.day, result.part);
+            }
+        }
+    }
+
+    #[test]
+    fn references_agree() {
+        for puzzle in &PUZZLES {
+            if let Err(disagreement) = aoc2025::differential::check(puzzle, &[1, 2], 0, 10, 5) {
+                panic!("{}", disagreement);
+            }
+        }
+    }
+}
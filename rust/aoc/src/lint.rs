@@ -0,0 +1,60 @@
+use aoc::puzzle;
+use aoc2025::input::{self, InputResolver};
+use aoc2025::{ParseError, SolveError};
+use clap::Args;
+use std::process::ExitCode;
+
+#[derive(Args)]
+pub struct LintArgs {
+    /// Day whose assumptions to check
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: u32,
+
+    /// Input file to check instead of looking one up ('-' for stdin)
+    input: Option<String>,
+
+    /// Which of the day's inputs to look up, e.g. 'example' or 'alt-account'
+    #[arg(short, long, default_value = input::DEFAULT_NAME, conflicts_with = "input")]
+    name: String,
+}
+
+pub fn lint(resolver: &InputResolver, args: &LintArgs) -> ExitCode {
+    let puzzle = puzzle(args.day).expect("Day should be validated by the argument parser");
+    let input = match resolver.read(puzzle.day, &args.name, args.input.as_deref()) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    for warning in input.warnings() {
+        eprintln!("{}", warning);
+    }
+
+    let mut problems: Vec<ParseError> = puzzle.lint(&input.contents);
+    // Solving no parts just parses, which catches anything malformed the lints skip. Its
+    // first error is usually one of theirs, so it's only worth checking once they pass.
+    if problems.is_empty() {
+        if let Err(SolveError::Parse(err)) = puzzle.solve(&input.contents, &[]) {
+            problems.push(err);
+        }
+    }
+
+    for problem in &problems {
+        println!("{}\n", problem.render(&input.path, &input.contents));
+    }
+    match problems.len() {
+        0 => {
+            println!("No problems found in {}", input.path);
+            ExitCode::SUCCESS
+        }
+        1 => {
+            println!("1 problem found in {}", input.path);
+            ExitCode::FAILURE
+        }
+        count => {
+            println!("{} problems found in {}", count, input.path);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use aoc::{puzzle, PUZZLES};
+use aoc2025::input::{self, Input, InputResolver};
+use aoc2025::answers::{AnswerStore, AnswersError, ANSWERS_FILE};
+use aoc2025::params::{self, ParamOverrides, ParamTable, ParamValue};
+use aoc2025::{Config, Puzzle};
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+mod batch;
+mod bench;
+mod diff;
+mod fetch;
+mod generate;
+mod lint;
+mod minimize;
+mod output;
+mod run;
+mod serve;
+mod submit;
+mod table;
+mod verify;
+mod watch;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with --all
+    Run {
+        #[command(flatten)]
+        selection: DaySelection,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        args: run::RunArgs,
+    },
+    /// Run every day on every input under a directory, each part in its own process
+    Batch(batch::BatchArgs),
+    /// Time parsing and each part over repeated runs
+    Bench {
+        #[command(flatten)]
+        selection: DaySelection,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        args: bench::BenchArgs,
+    },
+    /// Compare solvers against the naive reference solvers on random inputs
+    Diff {
+        #[command(flatten)]
+        selection: DaySelection,
+        #[command(flatten)]
+        args: diff::DiffArgs,
+    },
+    /// Download a day's input into the input directory
+    Fetch(fetch::FetchArgs),
+    /// Print a random input in a day's format
+    Generate(generate::GenerateArgs),
+    /// Check an input against every assumption the day's solver makes about it
+    Lint(lint::LintArgs),
+    /// Shrink an input the solver fails or disagrees with the reference on
+    Minimize(minimize::MinimizeArgs),
+    /// Solve inputs POSTed to a local HTTP/JSON service
+    Serve(serve::ServeArgs),
+    /// Submit an answer to the puzzle site
+    Submit(submit::SubmitArgs),
+    /// Check answers against the ones confirmed with `run --record`
+    Verify {
+        #[command(flatten)]
+        selection: DaySelection,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        answers: AnswersArgs,
+    },
+    /// Rebuild and rerun a day's tests and both parts whenever its sources or input change
+    Watch(watch::WatchArgs),
+}
+
+#[derive(Args)]
+struct DaySelection {
+    /// Day to run
+    #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: Option<u32>,
+
+    /// Run every day
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+}
+
+impl DaySelection {
+    fn puzzles(&self) -> Vec<&'static Puzzle> {
+        match self.day {
+            Some(day) => vec![puzzle(day).expect("Day should be validated by the argument parser")],
+            None => PUZZLES.iter().collect(),
+        }
+    }
+
+    fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Input file to use instead of looking one up ('-' for stdin)
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<String>,
+
+    /// Which of a day's inputs to look up, e.g. 'example' or 'alt-account'
+    #[arg(short, long, default_value = input::DEFAULT_NAME)]
+    name: String,
+}
+
+impl InputArgs {
+    fn read(&self, resolver: &InputResolver, puzzle: &Puzzle) -> Result<Input, String> {
+        let input = resolver
+            .read(puzzle.day, &self.name, self.input.as_deref())
+            .map_err(|err| format!("error: {}", err))?;
+        for warning in input.warnings() {
+            eprintln!("{}", warning);
+        }
+        Ok(input)
+    }
+}
+
+#[derive(Args)]
+struct ParamArgs {
+    /// Override one of the day's parameters, e.g. 'num_pairs=10' (repeatable)
+    #[arg(short = 'P', long = "param", value_name = "KEY=VALUE", value_parser = params::parse_override, conflicts_with = "all")]
+    overrides: Vec<(String, ParamValue)>,
+}
+
+impl ParamArgs {
+    /// The `[params.N]` table of `aoc.toml` with the flags' overrides on top.
+    fn table(&self, overrides: &ParamOverrides, day: u32) -> ParamTable {
+        let mut table = overrides.day(day);
+        table.extend(self.overrides.iter().cloned());
+        table
+    }
+}
+
+#[derive(Args)]
+struct AnswersArgs {
+    /// Answer store to use instead of answers.toml next to aoc.toml
+    #[arg(long)]
+    answers: Option<PathBuf>,
+}
+
+impl AnswersArgs {
+    fn path(&self, config: &Config) -> PathBuf {
+        match &self.answers {
+            Some(path) => path.clone(),
+            None => config.project_dir().join(ANSWERS_FILE),
+        }
+    }
+
+    fn load(&self, config: &Config) -> Result<(AnswerStore, PathBuf), AnswersError> {
+        let path = self.path(config);
+        Ok((AnswerStore::load(&path)?, path))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let resolver = InputResolver::new(config.dir.clone(), config.inputs.clone());
+
+    match cli.command {
+        Command::Run { selection, input, args } => run::run(&config, &resolver, &selection, &input, &args),
+        Command::Batch(args) => batch::batch(&args),
+        Command::Bench { selection, input, args } => bench::bench(&config, &resolver, &selection, &input, &args),
+        Command::Diff { selection, args } => diff::diff(&selection, &args),
+        Command::Fetch(args) => fetch::fetch(&config, &resolver, &args),
+        Command::Generate(args) => generate::generate(&args),
+        Command::Lint(args) => lint::lint(&resolver, &args),
+        Command::Minimize(args) => minimize::minimize(&resolver, &args),
+        Command::Serve(args) => serve::serve(&config, &args),
+        Command::Submit(args) => submit::submit(&config, &resolver, &args),
+        Command::Verify { selection, input, answers } => verify::verify(&config, &resolver, &selection, &input, &answers),
+        Command::Watch(args) => watch::watch(&resolver, &args),
+    }
+}
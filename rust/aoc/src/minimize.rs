@@ -0,0 +1,108 @@
+use aoc::puzzle;
+use aoc2025::input::{self, InputResolver};
+use aoc2025::minimize::{self, MinimizeError, Minimized};
+use aoc2025::Puzzle;
+use clap::Args;
+use std::fs;
+use std::panic;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Args)]
+pub struct MinimizeArgs {
+    /// Day to minimize an input for
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: u32,
+
+    /// Only minimize for this part, instead of the first one that fails
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Failing input file to shrink ('-' for stdin), instead of a generated one
+    #[arg(short, long, conflicts_with = "seed")]
+    input: Option<String>,
+
+    /// Seed of the generated input to shrink, as reported by `aoc diff`
+    #[arg(short, long, required_unless_present = "input")]
+    seed: Option<u64>,
+
+    /// Size of the generated input to shrink
+    #[arg(long, default_value_t = 5)]
+    size: usize,
+
+    /// Where to write the reproducer, instead of minimized_{day}_{part}.txt
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn contents(resolver: &InputResolver, puzzle: &Puzzle, args: &MinimizeArgs) -> Result<String, String> {
+    match args.seed {
+        Some(seed) => Ok(puzzle.generate(seed, args.size)),
+        None => {
+            let input = resolver.read(puzzle.day, input::DEFAULT_NAME, args.input.as_deref()).map_err(|err| format!("error: {}", err))?;
+            for warning in input.warnings() {
+                eprintln!("{}", warning);
+            }
+            Ok(input.contents)
+        }
+    }
+}
+
+/// Minimizes for `part`, or for the first part whose solver and reference disagree.
+fn first_failing(puzzle: &Puzzle, contents: &str, part: Option<u8>) -> Result<(u8, Minimized), MinimizeError> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut result = Err(MinimizeError::NoRecords { day: puzzle.day });
+    for part in parts {
+        result = minimize::minimize(puzzle, part, contents).map(|minimized| (part, minimized));
+        if !matches!(result, Err(MinimizeError::Agrees { .. })) {
+            break;
+        }
+    }
+    result
+}
+
+pub fn minimize(resolver: &InputResolver, args: &MinimizeArgs) -> ExitCode {
+    let puzzle = puzzle(args.day).expect("Day should be validated by the argument parser");
+    let contents = match contents(resolver, puzzle, args) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Every attempt that still fails may panic, so keep the default hook from printing each one
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let minimized = first_failing(puzzle, &contents, args.part);
+    panic::set_hook(hook);
+
+    let (part, minimized) = match minimized {
+        Ok(minimized) => minimized,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let path = args.output.clone().unwrap_or_else(|| PathBuf::from(format!("minimized_{}_{}.txt", puzzle.day, part)));
+    if let Err(err) = fs::write(&path, &minimized.contents) {
+        eprintln!("error: Could not write {}: {}", path.display(), err);
+        return ExitCode::FAILURE;
+    }
+
+    println!(
+        "Day {} part {}: shrank {} records to {}, written to {}",
+        puzzle.day,
+        part,
+        minimized.original_records,
+        minimized.records,
+        path.display()
+    );
+    println!("  got {}, expected {}", minimized.answer, minimized.expected);
+    ExitCode::SUCCESS
+}
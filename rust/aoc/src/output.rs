@@ -0,0 +1,131 @@
+use aoc2025::{Answer, Input, PartResult, YEAR};
+use std::time::Duration;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Tsv,
+}
+
+/// One part's answer, as emitted by `--format json` and `--format tsv`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnswerRecord {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub label: String,
+    pub answer: Answer,
+    pub elapsed_ns: u128,
+    /// Time spent parsing the day's input, shared by both parts.
+    pub parse_ns: u128,
+    pub input: String,
+    pub input_sha256: String,
+}
+
+impl AnswerRecord {
+    pub fn new(result: &PartResult, parse: Duration, input: &Input) -> Self {
+        AnswerRecord {
+            year: YEAR,
+            day: result.day,
+            part: result.part,
+            label: result.label.to_string(),
+            answer: result.answer.clone(),
+            elapsed_ns: result.elapsed.as_nanos(),
+            parse_ns: parse.as_nanos(),
+            input: input.path.clone(),
+            input_sha256: input.hash(),
+        }
+    }
+}
+
+const TSV_HEADER: [&str; 9] = ["year", "day", "part", "label", "answer", "elapsed_ns", "parse_ns", "input", "input_sha256"];
+
+/// Tabs and newlines would break the columns, so they're replaced with spaces.
+fn tsv_field(field: &str) -> String {
+    field.replace(['\t', '\n', '\r'], " ")
+}
+
+/// `record` as a line of `--format tsv`, in the order of `TSV_HEADER`.
+fn tsv_row(record: &AnswerRecord) -> String {
+    let fields = [
+        record.year.to_string(),
+        record.day.to_string(),
+        record.part.to_string(),
+        record.label.clone(),
+        record.answer.to_string(),
+        record.elapsed_ns.to_string(),
+        record.parse_ns.to_string(),
+        record.input.clone(),
+        record.input_sha256.clone(),
+    ];
+    fields.iter().map(|field| tsv_field(field)).collect::<Vec<String>>().join("\t")
+}
+
+pub fn print_records(format: Format, records: &[AnswerRecord]) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", serde_json::to_string_pretty(records).unwrap()),
+        Format::Tsv => {
+            println!("{}", TSV_HEADER.join("\t"));
+            for record in records {
+                println!("{}", tsv_row(record));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(answer: Answer) -> AnswerRecord {
+        AnswerRecord {
+            year: YEAR,
+            day: 6,
+            part: 2,
+            label: "Grand total".to_string(),
+            answer,
+            elapsed_ns: 1500,
+            parse_ns: 900,
+            input: "inputs/2025_6.txt".to_string(),
+            input_sha256: "ab12".to_string(),
+        }
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let answers = [
+            Answer::Signed(-3),
+            Answer::Unsigned(u64::MAX),
+            Answer::from(i128::MAX),
+            Answer::from("Christmas has been saved!"),
+            Answer::grid(["#..", ".#."]),
+        ];
+        for answer in answers {
+            let json = serde_json::to_string(&[record(answer.clone())]).unwrap();
+            let parsed: Vec<AnswerRecord> = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed[0].answer, answer);
+            assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        }
+
+        // The field names are what scripts reading the output rely on
+        let value = serde_json::to_value(record(Answer::Signed(7))).unwrap();
+        let mut fields: Vec<&str> = value.as_object().unwrap().keys().map(String::as_str).collect();
+        fields.sort();
+        let mut header = TSV_HEADER;
+        header.sort();
+        assert_eq!(fields, header);
+    }
+
+    #[test]
+    fn tsv_escaping() {
+        let mut escaped = record(Answer::grid(["#..", ".#."]));
+        escaped.label = "Tabs\tand\r\nnewlines".to_string();
+        let row = tsv_row(&escaped);
+        assert_eq!(row, "2025\t6\t2\tTabs and  newlines\t#.. .#.\t1500\t900\tinputs/2025_6.txt\tab12");
+        assert_eq!(row.split('\t').count(), TSV_HEADER.len());
+    }
+}
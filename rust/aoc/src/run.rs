@@ -0,0 +1,148 @@
+use crate::output::{self, AnswerRecord, Format};
+use crate::table::print_table;
+use crate::{AnswersArgs, DaySelection, InputArgs, ParamArgs};
+use aoc2025::params::ParamTable;
+use aoc2025::{Config, Input, InputResolver, PartResult, Puzzle, Solved};
+use clap::Args;
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Args)]
+pub struct RunArgs {
+    /// How to print answers
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Save the answers as confirmed, for `aoc verify` to check against
+    #[arg(long)]
+    record: bool,
+
+    /// Report how each part's memo tables did on stderr
+    #[arg(short, long)]
+    verbose: bool,
+
+    #[command(flatten)]
+    answers: AnswersArgs,
+
+    #[command(flatten)]
+    params: ParamArgs,
+}
+
+pub fn run_day(resolver: &InputResolver, puzzle: &Puzzle, input: &InputArgs, parts: &[u8], params: &ParamTable) -> Result<(Solved, Input), String> {
+    let input = input.read(resolver, puzzle)?;
+
+    let solved = puzzle.solve_with(&input.contents, parts, params)
+        .map_err(|err| err.render(&input.path, &input.contents))?;
+    Ok((solved, input))
+}
+
+fn print_summary(results: &[PartResult], parse: Duration) {
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|result| {
+            vec![
+                result.day.to_string(),
+                result.part.to_string(),
+                result.label.to_string(),
+                result.answer.to_string(),
+                format!("{:.5?}", result.elapsed),
+            ]
+        })
+        .collect();
+    print_table(&["Day", "Part", "Label", "Answer", "Time"], &rows);
+
+    let total: Duration = results.iter().map(|result| result.elapsed).sum::<Duration>() + parse;
+    println!("\n{} stars in {:.5?} ({:.5?} parsing)", results.len(), total, parse);
+}
+
+/// Adds `records` to the answer store, noting any answer that changed. Returns false if the store couldn't be updated.
+fn record_answers(config: &Config, answers: &AnswersArgs, records: &[AnswerRecord]) -> bool {
+    let (mut store, path) = match answers.load(config) {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return false;
+        }
+    };
+
+    for record in records {
+        if let Some(previous) = store.record(&record.input_sha256, record.day, record.part, &record.answer) {
+            if previous != record.answer.to_string() {
+                eprintln!("Day {} part {}: replaced {} with {} for {}", record.day, record.part, previous, record.answer, record.input);
+            }
+        }
+    }
+
+    match store.save(&path) {
+        Ok(()) => {
+            eprintln!("Recorded {} answers in {}", records.len(), path.display());
+            true
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            false
+        }
+    }
+}
+
+pub fn run(config: &Config, resolver: &InputResolver, selection: &DaySelection, input: &InputArgs, args: &RunArgs) -> ExitCode {
+    let text = args.format == Format::Text;
+
+    let mut results: Vec<PartResult> = Vec::new();
+    let mut parse = Duration::ZERO;
+    let mut records: Vec<AnswerRecord> = Vec::new();
+    let mut failed = false;
+    for puzzle in selection.puzzles() {
+        if selection.all && text {
+            println!("\nDay {}: {}", puzzle.day, puzzle.title);
+        }
+
+        let params = args.params.table(&config.params, puzzle.day);
+        // Answers to a variant of the puzzle shouldn't be confirmed as the puzzle's
+        if args.record && !params.is_empty() {
+            eprintln!("error: Day {} has parameter overrides, so its answers can't be recorded", puzzle.day);
+            failed = true;
+            continue;
+        }
+
+        match run_day(resolver, puzzle, input, &selection.parts(), &params) {
+            Ok((solved, day_input)) => {
+                if text {
+                    println!("Parsed in {:.5?}", solved.parse);
+                }
+                for result in &solved.parts {
+                    if text {
+                        println!("{}", result);
+                    }
+                    if args.verbose {
+                        for stats in &result.memo {
+                            eprintln!("Day {} part {} memo {}", result.day, result.part, stats);
+                        }
+                    }
+                    records.push(AnswerRecord::new(result, solved.parse, &day_input));
+                }
+                parse += solved.parse;
+                results.extend(solved.parts);
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                failed = true;
+            }
+        }
+    }
+
+    if selection.all && text {
+        print_summary(&results, parse);
+    }
+    output::print_records(args.format, &records);
+
+    if args.record && !records.is_empty() && !record_answers(config, &args.answers, &records) {
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
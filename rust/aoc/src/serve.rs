@@ -0,0 +1,486 @@
+use crate::output::AnswerRecord;
+use aoc::puzzle;
+use aoc2025::params::{ParamOverrides, ParamTable};
+use aoc2025::parse::Span;
+use aoc2025::{Config, Input, ParseError, Puzzle, SolveError, Solved, Unsupported, YEAR};
+use clap::Args;
+use serde::Serialize;
+use serde_json::json;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::process::ExitCode;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Largest input accepted, well above any real puzzle input.
+const MAX_BODY: usize = 16 << 20;
+/// How long a client may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Args)]
+pub struct ServeArgs {
+    /// Port to listen on, on localhost only
+    #[arg(short, long, default_value_t = 2025)]
+    port: u16,
+
+    /// How many solves may run at once; more are turned away with 503. Connections
+    /// aren't limited [default: number of CPUs]
+    #[arg(short, long)]
+    workers: Option<usize>,
+
+    /// Seconds a solve may take before its request gets a 504
+    #[arg(short, long, default_value_t = 30)]
+    timeout: u64,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+/// Counts for one day and part.
+#[derive(Debug, Clone, Default, Serialize)]
+struct PartMetrics {
+    solves: u64,
+    errors: u64,
+    timeouts: u64,
+    panics: u64,
+    total_ns: u128,
+    max_ns: u128,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Metrics {
+    requests: u64,
+    /// Responses sent, by status code.
+    responses: BTreeMap<u16, u64>,
+    /// Solves turned away because every worker was busy.
+    rejected: u64,
+    /// Solves running now, including ones whose request already timed out.
+    in_flight: usize,
+    /// By `"{day}/{part}"`.
+    parts: BTreeMap<String, PartMetrics>,
+}
+
+struct Server {
+    params: ParamOverrides,
+    workers: usize,
+    timeout: Duration,
+    started: Instant,
+    metrics: Mutex<Metrics>,
+}
+
+/// A worker taken for one solve, given back when the solve finishes, even if its
+/// request has long since timed out.
+struct Slot(Arc<Server>);
+
+impl Slot {
+    fn take(server: &Arc<Server>) -> Option<Slot> {
+        let mut metrics = server.metrics.lock().unwrap();
+        if metrics.in_flight >= server.workers {
+            metrics.rejected += 1;
+            return None;
+        }
+        metrics.in_flight += 1;
+        Some(Slot(Arc::clone(server)))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.metrics.lock().unwrap().in_flight -= 1;
+    }
+}
+
+/// Where a problem is in the input, for clients to point at.
+#[derive(Debug, Clone, Serialize)]
+struct Diagnostic {
+    line: usize,
+    column: usize,
+    found: String,
+    /// What should have been there instead, if the input is malformed rather than unsupported.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
+    message: String,
+}
+
+impl Diagnostic {
+    fn new(err: &ParseError) -> Self {
+        Diagnostic { line: err.line, column: err.column, found: err.text.clone(), expected: Some(err.expected.clone()), message: err.to_string() }
+    }
+
+    fn unsupported(err: &Unsupported) -> Self {
+        let Span { line, column, text } = &err.span;
+        Diagnostic { line: *line, column: *column, found: text.clone(), expected: None, message: err.to_string() }
+    }
+}
+
+/// A solved part, with what normalizing the input changed and any lint findings.
+#[derive(Debug, Serialize)]
+struct SolveResponse {
+    #[serde(flatten)]
+    record: AnswerRecord,
+    warnings: Vec<String>,
+    lints: Vec<Diagnostic>,
+}
+
+fn error(status: u16, kind: &str, message: impl Into<String>) -> (u16, serde_json::Value) {
+    (status, json!({ "error": kind, "message": message.into() }))
+}
+
+fn read_request(stream: &mut TcpStream) -> Result<Request, (u16, serde_json::Value)> {
+    let bad_request = |message: &str| error(400, "bad-request", message);
+    let mut reader = BufReader::new(stream.try_clone().map_err(|err| bad_request(&err.to_string()))?);
+
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(|err| bad_request(&err.to_string()))?;
+    let mut request_line = line.split_whitespace();
+    let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+        return Err(bad_request("expected a request line"));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut length = 0;
+    let mut expect_continue = false;
+    loop {
+        line.clear();
+        reader.read_line(&mut line).map_err(|err| bad_request(&err.to_string()))?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(bad_request("expected a header"));
+        };
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => length = value.trim().parse().map_err(|_| bad_request("expected a Content-Length"))?,
+            "expect" => expect_continue = value.trim().eq_ignore_ascii_case("100-continue"),
+            _ => {}
+        }
+    }
+
+    if length > MAX_BODY {
+        return Err(error(413, "too-large", format!("inputs are limited to {} bytes", MAX_BODY)));
+    }
+    // curl waits for this before sending anything bigger than a kilobyte
+    if expect_continue {
+        let _ = stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n");
+    }
+
+    // grown as the body arrives rather than sized from a Content-Length nothing's backed yet
+    let mut body = Vec::new();
+    reader.take(length as u64).read_to_end(&mut body).map_err(|err| bad_request(&err.to_string()))?;
+    if body.len() < length {
+        return Err(bad_request("the body is shorter than its Content-Length"));
+    }
+    Ok(Request { method, path, body })
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+/// What a solve thread sends back: the answer or error, and the lint findings.
+type Solve = Result<(Result<Solved, SolveError>, Vec<ParseError>), String>;
+
+/// Solves `part` of `puzzle` on its own thread, so a request can give up on it after the
+/// timeout. A solve that overruns keeps its worker until it finishes, since threads can't
+/// be stopped.
+fn solve(server: &Arc<Server>, puzzle: &'static Puzzle, part: u8, body: Vec<u8>) -> (u16, serde_json::Value) {
+    let Ok(contents) = String::from_utf8(body) else {
+        return error(400, "bad-request", "the input isn't UTF-8");
+    };
+    let input = Input::new("<request>".to_string(), contents);
+    let params: ParamTable = server.params.day(puzzle.day);
+    let Some(slot) = Slot::take(server) else {
+        return error(503, "busy", format!("all {} workers are busy", server.workers));
+    };
+
+    let (sender, receiver) = mpsc::channel::<Solve>();
+    let contents = input.contents.clone();
+    thread::spawn(move || {
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| (puzzle.solve_with(&contents, &[part], &params), puzzle.lint(&contents))));
+        // Given back before answering, so the client's next request can have it
+        drop(slot);
+        let _ = sender.send(solved.map_err(panic_message));
+    });
+
+    let key = format!("{}/{}", puzzle.day, part);
+    let received = receiver.recv_timeout(server.timeout);
+    let mut metrics = server.metrics.lock().unwrap();
+    let counts = metrics.parts.entry(key).or_default();
+    counts.solves += 1;
+
+    match received {
+        Ok(Ok((Ok(solved), lints))) => {
+            let result = &solved.parts[0];
+            counts.total_ns += result.elapsed.as_nanos();
+            counts.max_ns = counts.max_ns.max(result.elapsed.as_nanos());
+            let response = SolveResponse {
+                record: AnswerRecord::new(result, solved.parse, &input),
+                warnings: input.warnings().collect(),
+                lints: lints.iter().map(Diagnostic::new).collect(),
+            };
+            (200, serde_json::to_value(response).unwrap())
+        }
+        Ok(Ok((Err(err), lints))) => {
+            counts.errors += 1;
+            let (status, kind, diagnostics) = match &err {
+                SolveError::Parse(err) => (422, "parse", vec![Diagnostic::new(err)]),
+                SolveError::Overflow(_) => (422, "overflow", Vec::new()),
+                SolveError::Unsupported(err) => (422, "unsupported", vec![Diagnostic::unsupported(err)]),
+                SolveError::Params(_) => (500, "params", Vec::new()),
+            };
+            let lints: Vec<Diagnostic> = lints.iter().map(Diagnostic::new).collect();
+            (status, json!({ "error": kind, "message": err.to_string(), "diagnostics": diagnostics, "lints": lints }))
+        }
+        Ok(Err(message)) => {
+            counts.panics += 1;
+            error(500, "panic", message)
+        }
+        Err(_) => {
+            counts.timeouts += 1;
+            error(504, "timeout", format!("no answer after {}s; the solve keeps its worker until it finishes", server.timeout.as_secs()))
+        }
+    }
+}
+
+/// `POST /2025/{day}/{part}`, `GET /health` and `GET /metrics`.
+fn respond(server: &Arc<Server>, request: Request) -> (u16, serde_json::Value) {
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/health") => {
+            let in_flight = server.metrics.lock().unwrap().in_flight;
+            (200, json!({ "status": "ok", "uptime_s": server.started.elapsed().as_secs(), "workers": server.workers, "in_flight": in_flight }))
+        }
+        ("GET", "/metrics") => {
+            let metrics = server.metrics.lock().unwrap();
+            let mut value = serde_json::to_value(&*metrics).unwrap();
+            value["uptime_s"] = json!(server.started.elapsed().as_secs());
+            (200, value)
+        }
+        (method, path) => {
+            let solve_path = path
+                .strip_prefix(&format!("/{}/", YEAR))
+                .and_then(|rest| rest.split_once('/'))
+                .and_then(|(day, part)| Some((day.parse().ok().and_then(puzzle)?, part.parse::<u8>().ok().filter(|part| (1..=2).contains(part))?)));
+            match (method, solve_path) {
+                ("POST", Some((puzzle, part))) => solve(server, puzzle, part, request.body),
+                (_, Some(_)) => error(405, "method-not-allowed", "POST the input to solve it"),
+                _ => error(404, "not-found", format!("expected POST /{}/{{day}}/{{part}}, GET /health or GET /metrics", YEAR)),
+            }
+        }
+    }
+}
+
+fn handle(server: &Arc<Server>, mut stream: TcpStream) {
+    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+    server.metrics.lock().unwrap().requests += 1;
+    let (status, body) = match read_request(&mut stream) {
+        Ok(request) => respond(server, request),
+        Err(response) => response,
+    };
+    *server.metrics.lock().unwrap().responses.entry(status).or_default() += 1;
+
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Content",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Gateway Timeout",
+    };
+    let body = body.to_string();
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    );
+}
+
+/// Answers requests until the process is killed. Every connection gets its own thread,
+/// so `--workers` bounds the solves running at once but not the connections: the rest
+/// only read their request, for at most `READ_TIMEOUT`, or wait on a solve, for at most
+/// `--timeout`. It only listens on localhost, for one user's tools rather than the
+/// open internet.
+pub fn serve(config: &Config, args: &ServeArgs) -> ExitCode {
+    let listener = match TcpListener::bind((Ipv4Addr::LOCALHOST, args.port)) {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("error: Could not listen on port {}: {}", args.port, err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let workers = args.workers.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get())).max(1);
+    let server = Arc::new(Server {
+        params: config.params.clone(),
+        workers,
+        timeout: Duration::from_secs(args.timeout),
+        started: Instant::now(),
+        metrics: Mutex::default(),
+    });
+    eprintln!("Solving on http://{} with {} workers; POST an input to /{}/{{day}}/{{part}}", listener.local_addr().unwrap(), workers, YEAR);
+
+    for stream in listener.incoming().flatten() {
+        let server = Arc::clone(&server);
+        thread::spawn(move || handle(&server, stream));
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc2025::params::NoParams;
+    use aoc2025::{Example, Solution};
+    use std::net::Shutdown;
+
+    /// A day whose part 1 outlasts any test timeout and whose part 2 panics.
+    struct Broken;
+
+    impl Solution for Broken {
+        const DAY: u32 = 0;
+        const TITLE: &'static str = "Broken";
+        const LABELS: [&'static str; 2] = ["Slow", "Panics"];
+        const EXAMPLES: &'static [Example] = &[];
+
+        type Input = ();
+        type Part1 = i64;
+        type Part2 = i64;
+        type Params = NoParams;
+
+        fn parse(_contents: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+        fn part1(_input: &()) -> Result<i64, SolveError> {
+            thread::sleep(Duration::from_secs(1));
+            Ok(0)
+        }
+        fn part2(_input: &()) -> Result<i64, SolveError> {
+            panic!("part 2 is broken");
+        }
+        fn reference1(_contents: &str) -> i64 {
+            0
+        }
+        fn reference2(_contents: &str) -> i64 {
+            0
+        }
+        fn generate(_rng: &mut aoc2025::Rng, _size: usize) -> String {
+            String::new()
+        }
+    }
+
+    static BROKEN: Puzzle = Puzzle::of::<Broken>();
+
+    fn server(workers: usize, timeout: Duration) -> Arc<Server> {
+        Arc::new(Server { params: ParamOverrides::default(), workers, timeout, started: Instant::now(), metrics: Mutex::default() })
+    }
+
+    /// Sends `request` over a loopback connection and returns the status and body `handle` answers with.
+    fn exchange(server: &Arc<Server>, request: &[u8]) -> (u16, serde_json::Value) {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        client.write_all(request).unwrap();
+        client.shutdown(Shutdown::Write).unwrap();
+        let (stream, _) = listener.accept().unwrap();
+        handle(server, stream);
+
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    fn post(path: &str, body: &str) -> Vec<u8> {
+        format!("POST {} HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}", path, body.len(), body).into_bytes()
+    }
+
+    #[test]
+    fn solves() {
+        let server = server(1, Duration::from_secs(30));
+        let example = &puzzle(1).unwrap().examples[0];
+        let (status, body) = exchange(&server, &post("/2025/1/1", example.input));
+        assert_eq!(status, 200);
+        assert_eq!(body["answer"].to_string(), example.part1.unwrap());
+        assert_eq!(server.metrics.lock().unwrap().parts["1/1"].solves, 1);
+    }
+
+    #[test]
+    fn rejects_bad_requests() {
+        let server = server(1, Duration::from_secs(30));
+        assert_eq!(exchange(&server, b"GET /nowhere HTTP/1.1\r\n\r\n").0, 404);
+        assert_eq!(exchange(&server, &post("/2025/13/1", "")).0, 404);
+        assert_eq!(exchange(&server, b"GET /2025/1/1 HTTP/1.1\r\n\r\n").0, 405);
+        assert_eq!(exchange(&server, b"POST /2025/1/1 HTTP/1.1\r\nContent-Length: 99999999\r\n\r\n").0, 413);
+        assert_eq!(exchange(&server, b"POST /2025/1/1 HTTP/1.1\r\nContent-Length: 10\r\n\r\nL1\n").0, 400);
+        assert_eq!(exchange(&server, b"\r\n").0, 400);
+    }
+
+    #[test]
+    fn reports_malformed_input() {
+        let server = server(1, Duration::from_secs(30));
+        let (status, body) = exchange(&server, &post("/2025/1/1", "L1\nX2\n"));
+        assert_eq!(status, 422);
+        assert_eq!(body["error"], "parse");
+        assert_eq!(body["diagnostics"][0]["line"], 2);
+
+        let (status, body) = exchange(&server, &post("/2025/12/1", "0:\n##\n#.\n\n1000x1000: 300000\n"));
+        assert_eq!((status, body["error"].as_str()), (422, Some("unsupported")));
+        assert_eq!(body["diagnostics"][0]["found"], "1000x1000");
+        assert!(body["diagnostics"][0].get("expected").is_none());
+    }
+
+    #[test]
+    fn frees_the_worker_before_answering() {
+        let server = server(1, Duration::from_secs(30));
+        for _ in 0..20 {
+            assert_eq!(exchange(&server, &post("/2025/1/1", "L1\n")).0, 200);
+        }
+    }
+
+    #[test]
+    fn turns_away_solves_when_busy() {
+        let server = server(1, Duration::from_secs(30));
+        let busy = Slot::take(&server).unwrap();
+        let (status, body) = exchange(&server, &post("/2025/1/1", "L1\n"));
+        assert_eq!((status, body["error"].as_str()), (503, Some("busy")));
+        assert_eq!(server.metrics.lock().unwrap().rejected, 1);
+
+        drop(busy);
+        assert_eq!(exchange(&server, &post("/2025/1/1", "L1\n")).0, 200);
+    }
+
+    #[test]
+    fn times_out() {
+        let server = server(1, Duration::from_millis(50));
+        let (status, _) = solve(&server, &BROKEN, 1, Vec::new());
+        assert_eq!(status, 504);
+        // the overrunning solve still holds its worker
+        assert_eq!(server.metrics.lock().unwrap().in_flight, 1);
+        assert_eq!(solve(&server, &BROKEN, 1, Vec::new()).0, 503);
+    }
+
+    #[test]
+    fn reports_panics() {
+        let server = server(1, Duration::from_secs(30));
+        let (status, body) = solve(&server, &BROKEN, 2, Vec::new());
+        assert_eq!((status, body["message"].as_str()), (500, Some("part 2 is broken")));
+        assert_eq!(server.metrics.lock().unwrap().parts["0/2"].panics, 1);
+    }
+}
@@ -0,0 +1,90 @@
+use aoc::puzzle;
+use aoc2025::client::{Client, SubmissionHistory, Verdict, HISTORY_FILE};
+use aoc2025::input::{self, InputResolver};
+use aoc2025::{Config, Puzzle};
+use clap::Args;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Args)]
+pub struct SubmitArgs {
+    /// Day to submit an answer for
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: u32,
+
+    /// Part the answer is for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Answer to submit, solved from the day's input if not given
+    answer: Option<String>,
+
+    /// Input file to solve instead of looking one up ('-' for stdin)
+    #[arg(short, long, conflicts_with = "answer")]
+    input: Option<String>,
+
+    /// Which of the day's inputs to solve, e.g. 'alt-account'
+    #[arg(short, long, default_value = input::DEFAULT_NAME)]
+    name: String,
+
+    /// Submission history to use instead of submissions.toml next to aoc.toml
+    #[arg(long)]
+    history: Option<PathBuf>,
+}
+
+fn solve(resolver: &InputResolver, puzzle: &Puzzle, args: &SubmitArgs) -> Result<String, String> {
+    let input = resolver
+        .read(puzzle.day, &args.name, args.input.as_deref())
+        .map_err(|err| format!("error: {}", err))?;
+    for warning in input.warnings() {
+        eprintln!("{}", warning);
+    }
+    let mut solved = puzzle
+        .solve(&input.contents, &[args.part])
+        .map_err(|err| err.render(&input.path, &input.contents))?;
+    Ok(solved.parts.remove(0).answer.to_string())
+}
+
+pub fn submit(config: &Config, resolver: &InputResolver, args: &SubmitArgs) -> ExitCode {
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let puzzle = puzzle(args.day).expect("Day should be validated by the argument parser");
+            match solve(resolver, puzzle, args) {
+                Ok(answer) => answer,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    let path = args.history.clone().unwrap_or_else(|| config.project_dir().join(HISTORY_FILE));
+    let submitted = Client::from_env(&config.client).and_then(|client| {
+        let mut history = SubmissionHistory::load(&path)?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let submission = client.submit(&mut history, args.day, args.part, &answer, now)?;
+        history.save(&path)?;
+        Ok(submission)
+    });
+
+    match submitted {
+        Ok(submission) => {
+            println!("Day {} part {}: {} is {}", args.day, args.part, answer, submission.verdict);
+            if submission.wait > 0 {
+                println!("Wait {}s before submitting again", submission.wait);
+            }
+            if submission.verdict == Verdict::Correct {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,30 @@
+/// Prints `rows` as a plain-text table with left-aligned columns.
+pub fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .chain([header[col].len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let format_row = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!();
+    println!("{}", format_row(&header.iter().map(|cell| cell.to_string()).collect::<Vec<String>>()));
+    println!("{}", widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<String>>().join("-+-"));
+    for row in rows {
+        println!("{}", format_row(row));
+    }
+}
@@ -0,0 +1,71 @@
+use crate::run::run_day;
+use crate::table::print_table;
+use crate::{AnswersArgs, DaySelection, InputArgs};
+use aoc2025::params::ParamTable;
+use aoc2025::{Config, InputResolver};
+use std::process::ExitCode;
+
+pub fn verify(config: &Config, resolver: &InputResolver, selection: &DaySelection, input: &InputArgs, answers: &AnswersArgs) -> ExitCode {
+    let (store, path) = match answers.load(config) {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    if store.is_empty() {
+        eprintln!("warning: No confirmed answers in {}; record some with `aoc run --record`", path.display());
+    }
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let (mut matched, mut mismatched, mut unconfirmed) = (0, 0, 0);
+    let mut failed = false;
+    for puzzle in selection.puzzles() {
+        let (solved, day_input) = match run_day(resolver, puzzle, input, &selection.parts(), &ParamTable::new()) {
+            Ok(ran) => ran,
+            Err(err) => {
+                eprintln!("{}", err);
+                failed = true;
+                continue;
+            }
+        };
+
+        let hash = day_input.hash();
+        for result in solved.parts {
+            let answer = result.answer.to_string();
+            let expected = store.get(&hash, result.day, result.part);
+            let status = match expected {
+                Some(expected) if expected == answer => {
+                    matched += 1;
+                    "ok"
+                }
+                Some(_) => {
+                    mismatched += 1;
+                    "MISMATCH"
+                }
+                None => {
+                    unconfirmed += 1;
+                    "unconfirmed"
+                }
+            };
+
+            rows.push(vec![
+                result.day.to_string(),
+                result.part.to_string(),
+                day_input.path.clone(),
+                expected.unwrap_or("-").to_string(),
+                answer,
+                status.to_string(),
+            ]);
+        }
+    }
+
+    print_table(&["Day", "Part", "Input", "Expected", "Answer", "Status"], &rows);
+    println!("\n{} ok, {} mismatched, {} unconfirmed", matched, mismatched, unconfirmed);
+
+    if failed || mismatched > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
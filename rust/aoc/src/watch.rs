@@ -0,0 +1,279 @@
+use crate::output::AnswerRecord;
+use crate::table::print_table;
+use aoc::puzzle;
+use aoc2025::input::{self, InputResolver};
+use clap::Args;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+/// How long to wait for a burst of changes (e.g. an editor's save) to settle before rerunning.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Day to watch
+    #[arg(value_parser = clap::value_parser!(u32).range(1..=12))]
+    day: u32,
+
+    /// Input file to watch instead of looking one up
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Which of the day's inputs to look up, e.g. 'example' or 'alt-account'
+    #[arg(short, long, default_value = input::DEFAULT_NAME)]
+    name: String,
+}
+
+/// The files a day's run depends on.
+struct Watched {
+    src: PathBuf,
+    fixtures: PathBuf,
+    input: PathBuf,
+}
+
+impl Watched {
+    fn new(resolver: &InputResolver, args: &WatchArgs) -> Result<Self, String> {
+        let day = args.day;
+        let input = match &args.input {
+            Some(path) if path.as_os_str() == "-" => {
+                return Err("error: Can't watch stdin; pass an input file instead".to_string());
+            }
+            Some(path) => path.clone(),
+            None => resolver.resolve(day, &args.name).map_err(|err| format!("error: {}", err))?,
+        };
+        let input = input
+            .canonicalize()
+            .map_err(|err| format!("error: Couldn't read {}: {}", input.display(), err))?;
+
+        // The watch is only useful from a checkout, so the day's sources are found next to this crate's
+        let day_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(day.to_string());
+        let day_dir = day_dir
+            .canonicalize()
+            .map_err(|err| format!("error: Couldn't find day {}'s sources at {}: {}", day, day_dir.display(), err))?;
+        Ok(Watched { src: day_dir.join("src"), fixtures: day_dir.join("fixtures"), input })
+    }
+
+    /// Watches the input's directory rather than the file itself, since editors often save by
+    /// replacing the file, which would end a watch on it.
+    fn watch(&self, watcher: &mut impl Watcher) -> notify::Result<()> {
+        watcher.watch(&self.src, RecursiveMode::Recursive)?;
+        if self.fixtures.is_dir() {
+            watcher.watch(&self.fixtures, RecursiveMode::Recursive)?;
+        }
+        watcher.watch(self.input.parent().unwrap(), RecursiveMode::NonRecursive)
+    }
+
+    fn affected_by(&self, event: &Event) -> bool {
+        if matches!(event.kind, EventKind::Access(_)) {
+            return false;
+        }
+        event.paths.iter().any(|path| {
+            path.starts_with(&self.src) || path.starts_with(&self.fixtures) || *path == self.input
+        })
+    }
+}
+
+/// A cargo command on this workspace, wherever `aoc watch` was started from. It still runs
+/// in the current directory, so `aoc run` finds the same `aoc.toml`.
+fn cargo(args: &[&str]) -> Command {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("Cargo.toml");
+    let mut command = Command::new(env!("CARGO"));
+    command.args(args).arg("--manifest-path").arg(manifest).arg("--quiet");
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command
+}
+
+/// Rebuilds the day and runs its tests, then both parts through a freshly built `aoc run`.
+/// Returns `None` if anything failed; cargo's own output says what.
+fn rerun(day: u32, input: &Path) -> Option<Vec<AnswerRecord>> {
+    let package = format!("rust_2025_{}", day);
+    let tested = cargo(&["test", "-p", &package]).status().ok()?;
+    if !tested.success() {
+        eprintln!("Day {}'s tests failed", day);
+        return None;
+    }
+
+    let output = cargo(&["run", "-p", "aoc"])
+        .args(["--", "run", &day.to_string(), "--format", "json", "--input"])
+        .arg(input)
+        .stderr(Stdio::inherit())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    match serde_json::from_slice(&output.stdout) {
+        Ok(records) => Some(records),
+        Err(err) => {
+            eprintln!("error: Couldn't read the answers from `aoc run`: {}", err);
+            None
+        }
+    }
+}
+
+/// How `record`'s answer compares to the same part's in the `previous` run.
+fn change(previous: Option<&[AnswerRecord]>, record: &AnswerRecord) -> &'static str {
+    match previous.and_then(|previous| previous.iter().find(|old| old.part == record.part)) {
+        None => "new",
+        Some(before) if before.answer == record.answer => "same",
+        Some(_) => "CHANGED",
+    }
+}
+
+fn print_changes(previous: Option<&[AnswerRecord]>, records: &[AnswerRecord]) {
+    let rows: Vec<Vec<String>> = records
+        .iter()
+        .map(|record| {
+            let before = previous.and_then(|previous| previous.iter().find(|old| old.part == record.part));
+            vec![
+                record.part.to_string(),
+                record.label.clone(),
+                before.map_or("-".to_string(), |before| before.answer.to_string()),
+                record.answer.to_string(),
+                format!("{:.5?}", Duration::from_nanos(record.elapsed_ns as u64)),
+                change(previous, record).to_string(),
+            ]
+        })
+        .collect();
+    print_table(&["Part", "Label", "Previous", "Answer", "Time", "Change"], &rows);
+}
+
+/// Blocks until something `watched` depends on changes, then waits for the changes to settle.
+/// Returns false once the watcher has gone away.
+fn wait_for_change(events: &Receiver<notify::Result<Event>>, watched: &Watched) -> bool {
+    loop {
+        match events.recv() {
+            Ok(Ok(event)) if watched.affected_by(&event) => break,
+            Ok(Ok(_)) => {}
+            Ok(Err(err)) => eprintln!("warning: {}", err),
+            Err(_) => return false,
+        }
+    }
+
+    while events.recv_timeout(DEBOUNCE).is_ok() {}
+    true
+}
+
+pub fn watch(resolver: &InputResolver, args: &WatchArgs) -> ExitCode {
+    let puzzle = puzzle(args.day).expect("Day should be validated by the argument parser");
+    let watched = match Watched::new(resolver, args) {
+        Ok(watched) => watched,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (sender, events) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(sender) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(err) = watched.watch(&mut watcher) {
+        eprintln!("error: {}", err);
+        return ExitCode::FAILURE;
+    }
+
+    println!("Day {}: {}", puzzle.day, puzzle.title);
+    println!("Watching {}, {} and {}", watched.src.display(), watched.fixtures.display(), watched.input.display());
+
+    let mut previous: Option<Vec<AnswerRecord>> = None;
+    loop {
+        if let Some(records) = rerun(args.day, &watched.input) {
+            print_changes(previous.as_deref(), &records);
+            previous = Some(records);
+        }
+
+        println!("\nWaiting for changes...");
+        if !wait_for_change(&events, &watched) {
+            return ExitCode::SUCCESS;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc2025::{Answer, YEAR};
+    use notify::event::{AccessKind, CreateKind, ModifyKind};
+    use std::sync::mpsc::Sender;
+    use std::thread;
+
+    fn watched() -> Watched {
+        Watched { src: PathBuf::from("/aoc/7/src"), fixtures: PathBuf::from("/aoc/7/fixtures"), input: PathBuf::from("/inputs/2025_7.txt") }
+    }
+
+    fn event(kind: EventKind, path: &str) -> Event {
+        Event::new(kind).add_path(PathBuf::from(path))
+    }
+
+    fn modified(path: &str) -> Event {
+        event(EventKind::Modify(ModifyKind::Any), path)
+    }
+
+    #[test]
+    fn affected_by_test() {
+        let watched = watched();
+        assert!(watched.affected_by(&modified("/aoc/7/src/lib.rs")));
+        assert!(watched.affected_by(&modified("/aoc/7/src/generate/mod.rs")));
+        assert!(watched.affected_by(&modified("/aoc/7/fixtures/example.txt")));
+        assert!(watched.affected_by(&event(EventKind::Create(CreateKind::File), "/inputs/2025_7.txt")));
+
+        // Reads don't change anything, and the input's neighbours aren't watched for
+        assert!(!watched.affected_by(&event(EventKind::Access(AccessKind::Any), "/aoc/7/src/lib.rs")));
+        assert!(!watched.affected_by(&modified("/inputs/2025_8.txt")));
+        assert!(!watched.affected_by(&modified("/aoc/7/src.bak/lib.rs")));
+        assert!(!watched.affected_by(&modified("/aoc/17/src/lib.rs")));
+    }
+
+    #[test]
+    fn waits_for_a_relevant_change_to_settle() {
+        let (sender, events): (Sender<notify::Result<Event>>, _) = mpsc::channel();
+        sender.send(Ok(modified("/inputs/2025_8.txt"))).unwrap();
+        sender.send(Err(notify::Error::generic("dropped an event"))).unwrap();
+        let saving = thread::spawn(move || {
+            // An editor's save: several events in quick succession
+            for _ in 0..3 {
+                sender.send(Ok(modified("/aoc/7/src/lib.rs"))).unwrap();
+                thread::sleep(DEBOUNCE / 4);
+            }
+            sender
+        });
+
+        assert!(wait_for_change(&events, &watched()));
+        let sender = saving.join().unwrap();
+        assert!(events.try_recv().is_err(), "the whole burst should be used up");
+
+        drop(sender);
+        assert!(!wait_for_change(&events, &watched()));
+    }
+
+    #[test]
+    fn change_test() {
+        let record = |part: u8, answer: i64| AnswerRecord {
+            year: YEAR,
+            day: 7,
+            part,
+            label: String::new(),
+            answer: Answer::Signed(answer),
+            elapsed_ns: 0,
+            parse_ns: 0,
+            input: String::new(),
+            input_sha256: String::new(),
+        };
+        let previous = [record(1, 21)];
+        assert_eq!(change(None, &record(1, 21)), "new");
+        assert_eq!(change(Some(&previous), &record(1, 21)), "same");
+        assert_eq!(change(Some(&previous), &record(1, 22)), "CHANGED");
+        assert_eq!(change(Some(&previous), &record(2, 21)), "new");
+    }
+}
@@ -0,0 +1,47 @@
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// A batch directory holding `contents` as day 1's `2025_1.txt`.
+fn batch_dir(name: &str, contents: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-batch-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("2025_1.txt"), contents).unwrap();
+    dir
+}
+
+fn batch(dir: &PathBuf, timeout: &str) -> (Output, Vec<Value>) {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["batch", "--format", "json", "--timeout", timeout])
+        .arg(dir)
+        .output()
+        .unwrap();
+    let records = serde_json::from_slice(&output.stdout).unwrap();
+    (output, records)
+}
+
+#[test]
+fn answers() {
+    let example = &aoc::puzzle(1).unwrap().examples[0];
+    let dir = batch_dir("answers", example.input);
+    let (output, records) = batch(&dir, "60");
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(output.status.success());
+    let answers: Vec<String> = records.iter().map(|record| record["answer"].to_string()).collect();
+    assert_eq!(answers, [example.part1.unwrap(), example.part2.unwrap()]);
+}
+
+#[test]
+fn times_out() {
+    // big enough that neither part can finish before it's first checked on
+    let dir = batch_dir("timeout", &"L50\n".repeat(1_000_000));
+    let (output, records) = batch(&dir, "0");
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(records.len(), 2);
+    assert!(records.iter().all(|record| record["status"] == "timeout"), "{:?}", records);
+}
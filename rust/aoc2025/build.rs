@@ -0,0 +1,69 @@
+//! With the `embed` feature, writes `embedded.rs` listing each day's input for
+//! `include_bytes!`, so the binaries can solve them without any input files.
+//!
+//! Inputs are taken from, in order:
+//! 1. `$AOC_EMBED_DIR`, relative to the workspace
+//! 2. the `[inputs]` dir of the nearest `aoc.toml` above the workspace
+//! 3. `../../Inputs`, relative to the workspace
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const YEAR: u32 = 2025;
+const EMBED_DIR_VAR: &str = "AOC_EMBED_DIR";
+
+/// The `[inputs]` dir of the nearest `aoc.toml` above `workspace`, relative to that file.
+fn config_dir(workspace: &Path) -> Option<PathBuf> {
+    let path = workspace.ancestors().map(|dir| dir.join("aoc.toml")).find(|path| path.is_file())?;
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let config: toml::Table = fs::read_to_string(&path).ok()?.parse().ok()?;
+    let dir = config.get("inputs")?.get("dir")?.as_str()?;
+    Some(path.parent()?.join(dir))
+}
+
+fn embed_dir(workspace: &Path) -> PathBuf {
+    match env::var_os(EMBED_DIR_VAR) {
+        Some(dir) => workspace.join(dir),
+        None => config_dir(workspace).unwrap_or_else(|| workspace.join("../../Inputs")),
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed={}", EMBED_DIR_VAR);
+
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    let workspace = manifest_dir.parent().unwrap();
+
+    let mut dir = String::new();
+    let mut inputs = String::new();
+    if env::var_os("CARGO_FEATURE_EMBED").is_some() {
+        let embed_dir = embed_dir(workspace);
+        let embed_dir = embed_dir.canonicalize().unwrap_or(embed_dir);
+        println!("cargo:rerun-if-changed={}", embed_dir.display());
+        dir = embed_dir.display().to_string();
+
+        for day in 1..=12 {
+            let path = embed_dir.join(format!("{}_{}.txt", YEAR, day));
+            if path.is_file() {
+                println!("cargo:rerun-if-changed={}", path.display());
+                writeln!(inputs, "    ({}, include_bytes!({:?})),", day, path.display().to_string()).unwrap();
+            } else {
+                println!("cargo:warning=No input for day {} at {}, so it isn't embedded", day, path.display());
+            }
+        }
+    }
+
+    let table = format!(
+        "/// Directory the inputs were embedded from, empty without the `embed` feature.\n\
+         pub const DIR: &str = {:?};\n\
+         /// Each embedded day and its input.\n\
+         pub const INPUTS: &[(u32, &[u8])] = &[\n{}];\n",
+        dir, inputs
+    );
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("embedded.rs");
+    fs::write(out, table).unwrap();
+}
@@ -0,0 +1,168 @@
+use num_bigint::BigInt;
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A part's answer, whatever its type, so every part can be printed, stored and
+/// compared the same way.
+///
+/// Integers are normalised to the smallest variant that holds them, so the same
+/// answer compares equal whether it was computed as `i64`, `i128` or a bignum.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    /// An integer too big for `i64` and `u64`.
+    Big(BigInt),
+    Text(String),
+    /// Rows of a picture, e.g. letters drawn in `#`s.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub fn grid<S: Into<String>>(rows: impl IntoIterator<Item = S>) -> Self {
+        Answer::Grid(rows.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Grids are printed a row per line; everything else on one line.
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => n.fmt(f),
+            Answer::Unsigned(n) => n.fmt(f),
+            Answer::Big(n) => n.fmt(f),
+            Answer::Text(text) => f.write_str(text),
+            Answer::Grid(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Signed(n)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        match i64::try_from(n) {
+            Ok(n) => Answer::Signed(n),
+            Err(_) => Answer::Unsigned(n),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::from(n as u64)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        Answer::from(BigInt::from(n))
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        if let Ok(n) = i64::try_from(&n) {
+            Answer::Signed(n)
+        } else if let Ok(n) = u64::try_from(&n) {
+            Answer::Unsigned(n)
+        } else {
+            Answer::Big(n)
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// Integers that fit are JSON numbers, bignums and text are strings, and grids are
+/// arrays of rows.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Signed(n) => serializer.serialize_i64(*n),
+            Answer::Unsigned(n) => serializer.serialize_u64(*n),
+            Answer::Big(n) => serializer.serialize_str(&n.to_string()),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Grid(rows) => rows.serialize(serializer),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Repr {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+/// The inverse of `Serialize`, except that strings holding an integer come back as numbers.
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Repr::deserialize(deserializer).map_err(|_| de::Error::custom("expected a number, string or list of rows"))? {
+            Repr::Signed(n) => Answer::Signed(n),
+            Repr::Unsigned(n) => Answer::Unsigned(n),
+            Repr::Text(text) => match text.parse::<BigInt>() {
+                Ok(n) => Answer::from(n),
+                Err(_) => Answer::Text(text),
+            },
+            Repr::Grid(rows) => Answer::Grid(rows),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalises_integers() {
+        assert_eq!(Answer::from(42u64), Answer::Signed(42));
+        assert_eq!(Answer::from(BigInt::from(-7)), Answer::Signed(-7));
+        assert_eq!(Answer::from(u64::MAX as i128), Answer::Unsigned(u64::MAX));
+        assert!(matches!(Answer::from(i128::MAX), Answer::Big(_)));
+    }
+
+    #[test]
+    fn displays() {
+        assert_eq!(Answer::from(3121910778619i64).to_string(), "3121910778619");
+        assert_eq!(Answer::from(i128::MAX).to_string(), "170141183460469231731687303715884105727");
+        assert_eq!(Answer::from("Christmas has been saved!").to_string(), "Christmas has been saved!");
+        assert_eq!(Answer::grid(["#..#", "####"]).to_string(), "#..#\n####");
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let answers = vec![
+            Answer::Signed(-5),
+            Answer::Unsigned(u64::MAX),
+            Answer::from(i128::MIN),
+            Answer::from("Christmas has been saved!"),
+            Answer::grid([".#.", "#.#"]),
+        ];
+
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(
+            json,
+            r##"[-5,18446744073709551615,"-170141183460469231731687303715884105728","Christmas has been saved!",[".#.","#.#"]]"##
+        );
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::Answer;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Default answer store, kept next to `aoc.toml`.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// A confirmed answer for one part of one input, kept as it's displayed so every
+/// kind of [`Answer`] is stored and compared the same way.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredAnswer {
+    pub input_sha256: String,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct AnswersFile {
+    #[serde(default, rename = "answer")]
+    answers: Vec<StoredAnswer>,
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Read { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, message: String },
+    Write { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Read { path, source } => write!(f, "Could not read {}: {}", path.display(), source),
+            AnswersError::Parse { path, message } => write!(f, "Invalid {}: {}", path.display(), message),
+            AnswersError::Write { path, source } => write!(f, "Could not write {}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AnswersError::Read { source, .. } | AnswersError::Write { source, .. } => Some(source),
+            AnswersError::Parse { .. } => None,
+        }
+    }
+}
+
+/// Confirmed answers keyed by input hash, day and part, so refactors can be checked
+/// against every input anyone has run, without needing the inputs themselves.
+///
+/// ```toml
+/// [[answer]]
+/// input_sha256 = "d793…"
+/// day = 3
+/// part = 1
+/// answer = "17430"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AnswerStore {
+    answers: Vec<StoredAnswer>,
+}
+
+impl AnswerStore {
+    /// Loads the store at `path`. A missing file is an empty store.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(AnswerStore::default()),
+            Err(source) => return Err(AnswersError::Read { path: path.to_path_buf(), source }),
+        };
+        let file: AnswersFile = toml::from_str(&text)
+            .map_err(|err| AnswersError::Parse { path: path.to_path_buf(), message: err.message().to_string() })?;
+
+        Ok(AnswerStore { answers: file.answers })
+    }
+
+    /// Writes the store to `path`, sorted so diffs stay small.
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        let mut answers = self.answers.clone();
+        answers.sort_by(|a, b| (a.day, &a.input_sha256, a.part).cmp(&(b.day, &b.input_sha256, b.part)));
+
+        let text = toml::to_string(&AnswersFile { answers }).unwrap();
+        fs::write(path, text).map_err(|source| AnswersError::Write { path: path.to_path_buf(), source })
+    }
+
+    pub fn get(&self, input_sha256: &str, day: u32, part: u8) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|stored| stored.input_sha256 == input_sha256 && stored.day == day && stored.part == part)
+            .map(|stored| stored.answer.as_str())
+    }
+
+    /// Records `answer` as confirmed, returning the answer it replaced, if any.
+    pub fn record(&mut self, input_sha256: &str, day: u32, part: u8, answer: &Answer) -> Option<String> {
+        match self.answers.iter_mut().find(|stored| stored.input_sha256 == input_sha256 && stored.day == day && stored.part == part) {
+            Some(stored) => Some(std::mem::replace(&mut stored.answer, answer.to_string())),
+            None => {
+                self.answers.push(StoredAnswer {
+                    input_sha256: input_sha256.to_string(),
+                    day,
+                    part,
+                    answer: answer.to_string(),
+                });
+                None
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_replaces_by_hash_day_and_part() {
+        let mut store = AnswerStore::default();
+
+        assert_eq!(store.record("abc", 3, 1, &Answer::Signed(357)), None);
+        assert_eq!(store.record("abc", 3, 2, &Answer::Signed(3121910778619)), None);
+        assert_eq!(store.record("abc", 3, 1, &Answer::Signed(358)), Some("357".to_string()));
+
+        assert_eq!(store.get("abc", 3, 1), Some("358"));
+        assert_eq!(store.get("abc", 4, 1), None);
+        assert_eq!(store.len(), 2);
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut store = AnswerStore::default();
+        store.record("def", 8, 2, &Answer::Signed(25272));
+        store.record("abc", 8, 1, &Answer::Signed(40));
+
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+        store.save(&path).unwrap();
+        let loaded = AnswerStore::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.get("abc", 8, 1), Some("40"));
+        assert_eq!(loaded.get("def", 8, 2), Some("25272"));
+    }
+}
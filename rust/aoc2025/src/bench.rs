@@ -0,0 +1,122 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How many untimed and timed runs to do of each stage.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions { warmup: 3, iterations: 20 }
+    }
+}
+
+/// A timed step of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub fn part(part: u8) -> Stage {
+        match part {
+            1 => Stage::Part1,
+            2 => Stage::Part2,
+            _ => panic!("Unknown part: {}", part),
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        })
+    }
+}
+
+/// Summary of repeated timings of one stage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "Need at least one sample");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / secs.len() as f64;
+
+        Stats {
+            samples: sorted.len(),
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Runs `f` `warmup` times untimed, then `iterations` times timed.
+pub fn measure<T>(options: &BenchOptions, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(f());
+    }
+
+    let samples: Vec<Duration> = (0..options.iterations.max(1))
+        .map(|_| {
+            let timer = Instant::now();
+            black_box(f());
+            timer.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(&samples)
+}
+
+/// Benchmark of one stage of one day.
+#[derive(Debug, Clone)]
+pub struct BenchResult {
+    pub day: u32,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_of_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].iter().map(|&ms| Duration::from_millis(ms)).collect();
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+}
@@ -0,0 +1,456 @@
+use crate::input::InputResolver;
+use crate::YEAR;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[cfg(test)]
+mod mock;
+
+/// Site inputs are downloaded from and answers submitted to, unless configured otherwise.
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// Environment variable overriding the base URL, checked before the config file.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Environment variable holding the value of the site's `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Default submission history, kept next to `aoc.toml`.
+pub const HISTORY_FILE: &str = "submissions.toml";
+/// How long to hold off after a wrong answer when the site doesn't say.
+const DEFAULT_WAIT: u64 = 60;
+/// The site asks automated tools to identify themselves.
+const USER_AGENT: &str = "github.com/brandonhippe/Advent-of-Code-2025";
+
+/// The `[client]` table of `aoc.toml`.
+///
+/// ```toml
+/// [client]
+/// base_url = "http://localhost:8080"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientConfig {
+    pub base_url: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    NoSession,
+    /// The request didn't get a response at all.
+    Request { url: String, message: String },
+    /// The site answered with an error, e.g. for a day that hasn't unlocked yet.
+    Status { url: String, status: u16, message: String },
+    /// The site answered, but not in a way we recognise.
+    UnexpectedResponse { url: String },
+    /// Refused locally, to stay within the site's limits.
+    TooSoon { wait: Duration },
+    AlreadySolved { day: u32, part: u8, answer: String },
+    AlreadySubmitted { day: u32, part: u8, answer: String, verdict: Verdict },
+    Read { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, message: String },
+    Write { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(f, "No session cookie; set {} to the value of adventofcode.com's 'session' cookie", SESSION_VAR),
+            ClientError::Request { url, message } => write!(f, "Request to {} failed: {}", url, message),
+            ClientError::Status { url, status, message } => write!(f, "{} returned {}: {}", url, status, message),
+            ClientError::UnexpectedResponse { url } => write!(f, "Did not recognise the response from {}", url),
+            ClientError::TooSoon { wait } => write!(f, "Submitting too often; try again in {}s", wait.as_secs()),
+            ClientError::AlreadySolved { day, part, answer } => write!(f, "Day {} part {} is already solved, with {}", day, part, answer),
+            ClientError::AlreadySubmitted { day, part, answer, verdict } => {
+                write!(f, "Already submitted {} for day {} part {}: {}", answer, day, part, verdict)
+            }
+            ClientError::Read { path, source } => write!(f, "Could not read {}: {}", path.display(), source),
+            ClientError::Parse { path, message } => write!(f, "Invalid {}: {}", path.display(), message),
+            ClientError::Write { path, source } => write!(f, "Could not write {}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Read { source, .. } | ClientError::Write { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// Not judged, because the last submission was too recent.
+    TooSoon,
+    /// Not judged, because the part is already solved or not unlocked yet.
+    WrongLevel,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooSoon => "too soon",
+            Verdict::WrongLevel => "wrong level",
+        })
+    }
+}
+
+impl Verdict {
+    /// Whether the site actually checked the answer.
+    pub fn judged(self) -> bool {
+        !matches!(self, Verdict::TooSoon | Verdict::WrongLevel)
+    }
+}
+
+/// Reads a verdict and how long to wait before the next submission out of the site's answer page.
+fn parse_verdict(page: &str) -> Option<(Verdict, u64)> {
+    if page.contains("That's the right answer") {
+        return Some((Verdict::Correct, 0));
+    }
+    if page.contains("That's not the right answer") {
+        let verdict = if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        };
+        return Some((verdict, parse_wait(page).unwrap_or(DEFAULT_WAIT)));
+    }
+    if page.contains("You gave an answer too recently") {
+        return Some((Verdict::TooSoon, parse_wait(page).unwrap_or(DEFAULT_WAIT)));
+    }
+    if page.contains("You don't seem to be solving the right level") {
+        return Some((Verdict::WrongLevel, 0));
+    }
+    None
+}
+
+/// Seconds from "You have 1m 5s left to wait" or "Please wait 5 minutes", if either is there.
+fn parse_wait(page: &str) -> Option<u64> {
+    if let Some(start) = page.find("You have ") {
+        let rest = &page[start + "You have ".len()..];
+        let end = rest.find(" left to wait")?;
+        return rest[..end]
+            .split_whitespace()
+            .map(|amount| {
+                let unit = amount.chars().last()?;
+                let number: u64 = amount[..amount.len() - unit.len_utf8()].parse().ok()?;
+                match unit {
+                    'h' => Some(number * 3600),
+                    'm' => Some(number * 60),
+                    's' => Some(number),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+
+    // ASCII only, so offsets into it are offsets into the page too
+    let page = page.to_ascii_lowercase();
+    let start = page.find("please wait ")?;
+    let mut words = page[start + "please wait ".len()..].split_whitespace();
+    let minutes = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    words.next()?.starts_with("minute").then_some(minutes * 60)
+}
+
+/// One answer sent to the site.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time, in seconds.
+    pub submitted_at: u64,
+    /// Seconds the site asked to wait before submitting again.
+    pub wait: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct HistoryFile {
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+/// Every answer submitted from this checkout, so the same wrong answer is never sent twice
+/// and the site's wait after a wrong answer is respected even across runs.
+#[derive(Debug, Clone, Default)]
+pub struct SubmissionHistory {
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionHistory {
+    /// Loads the history at `path`. A missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, ClientError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(SubmissionHistory::default()),
+            Err(source) => return Err(ClientError::Read { path: path.to_path_buf(), source }),
+        };
+        let file: HistoryFile = toml::from_str(&text)
+            .map_err(|err| ClientError::Parse { path: path.to_path_buf(), message: err.message().to_string() })?;
+
+        Ok(SubmissionHistory { submissions: file.submissions })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ClientError> {
+        let text = toml::to_string(&HistoryFile { submissions: self.submissions.clone() }).unwrap();
+        fs::write(path, text).map_err(|source| ClientError::Write { path: path.to_path_buf(), source })
+    }
+
+    /// The correct submission for `part` of `day`, if it's been solved.
+    pub fn solved(&self, day: u32, part: u8) -> Option<&Submission> {
+        self.submissions
+            .iter()
+            .find(|submission| submission.day == day && submission.part == part && submission.verdict == Verdict::Correct)
+    }
+
+    /// The site's judgement of `answer`, if it's been submitted before.
+    pub fn judged(&self, day: u32, part: u8, answer: &str) -> Option<&Submission> {
+        self.submissions.iter().rev().find(|submission| {
+            submission.day == day && submission.part == part && submission.answer == answer && submission.verdict.judged()
+        })
+    }
+
+    /// Unix time after which the site will accept another submission.
+    pub fn next_allowed(&self) -> u64 {
+        self.submissions.iter().map(|submission| submission.submitted_at + submission.wait).max().unwrap_or(0)
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+}
+
+/// Downloads inputs from and submits answers to the puzzle site, or anything that
+/// answers like it at `base_url`.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Client { agent, base_url: base_url.trim_end_matches('/').to_string(), session: session.to_string() }
+    }
+
+    /// Client for `$AOC_BASE_URL`, then `config`'s base URL, then the real site, with the session from `$AOC_SESSION`.
+    pub fn from_env(config: &ClientConfig) -> Result<Self, ClientError> {
+        let base_url = env::var(BASE_URL_VAR)
+            .ok()
+            .or_else(|| config.base_url.clone())
+            .unwrap_or_else(|| BASE_URL.to_string());
+        let session = env::var(SESSION_VAR).map_err(|_| ClientError::NoSession)?;
+        Ok(Client::new(&base_url, session.trim()))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn day_url(&self, day: u32) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    /// Body of a successful response, or the error the site gave.
+    fn read(url: &str, response: Result<ureq::http::Response<ureq::Body>, ureq::Error>) -> Result<String, ClientError> {
+        let request_error = |err: ureq::Error| ClientError::Request { url: url.to_string(), message: err.to_string() };
+        let mut response = response.map_err(request_error)?;
+        let body = response.body_mut().read_to_string().map_err(request_error)?;
+
+        let status = response.status().as_u16();
+        if status != 200 {
+            let message = body.lines().next().unwrap_or_default().trim().to_string();
+            return Err(ClientError::Status { url: url.to_string(), status, message });
+        }
+        Ok(body)
+    }
+
+    pub fn fetch_input(&self, day: u32) -> Result<String, ClientError> {
+        let url = format!("{}/input", self.day_url(day));
+        let response = self.agent.get(&url).header("Cookie", format!("session={}", self.session)).call();
+        Client::read(&url, response)
+    }
+
+    /// Path of input `name` for `day`, downloading it into the first of `resolver`'s
+    /// locations unless it's already there or `force` is set. Also returns whether it was downloaded.
+    pub fn download(&self, resolver: &InputResolver, day: u32, name: &str, force: bool) -> Result<(PathBuf, bool), ClientError> {
+        if !force {
+            if let Ok(path) = resolver.resolve(day, name) {
+                return Ok((path, false));
+            }
+        }
+
+        let contents = self.fetch_input(day)?;
+        let path = resolver.destination(day, name);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|source| ClientError::Write { path: dir.to_path_buf(), source })?;
+        }
+        fs::write(&path, contents).map_err(|source| ClientError::Write { path: path.clone(), source })?;
+        Ok((path, true))
+    }
+
+    /// Sends `answer` without checking the history first.
+    pub fn post_answer(&self, day: u32, part: u8, answer: &str) -> Result<(Verdict, u64), ClientError> {
+        let url = format!("{}/answer", self.day_url(day));
+        let level = part.to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", level.as_str()), ("answer", answer)]);
+
+        let page = Client::read(&url, response)?;
+        parse_verdict(&page).ok_or(ClientError::UnexpectedResponse { url })
+    }
+
+    /// Submits `answer` at unix time `now`, unless `history` shows the part is solved, the
+    /// answer has already been judged, or the site's wait since the last submission isn't up.
+    /// Anything actually sent is added to `history`.
+    pub fn submit(&self, history: &mut SubmissionHistory, day: u32, part: u8, answer: &str, now: u64) -> Result<Submission, ClientError> {
+        if let Some(solved) = history.solved(day, part) {
+            return Err(ClientError::AlreadySolved { day, part, answer: solved.answer.clone() });
+        }
+        if let Some(judged) = history.judged(day, part, answer) {
+            return Err(ClientError::AlreadySubmitted { day, part, answer: answer.to_string(), verdict: judged.verdict });
+        }
+        let next = history.next_allowed();
+        if now < next {
+            return Err(ClientError::TooSoon { wait: Duration::from_secs(next - now) });
+        }
+
+        let (verdict, wait) = self.post_answer(day, part, answer)?;
+        let submission = Submission { day, part, answer: answer.to_string(), verdict, submitted_at: now, wait };
+        history.submissions.push(submission.clone());
+        Ok(submission)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mock::MockServer;
+
+    const SESSION: &str = "cafebabe";
+
+    fn server() -> MockServer {
+        MockServer::start(SESSION)
+            .with_input(1, "L68\nR48\n")
+            .with_answer(1, 1, "3")
+            .with_answer(1, 2, "6")
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_inputs() {
+        let server = server();
+        let client = Client::new(&server.url(), SESSION);
+
+        assert_eq!(client.fetch_input(1).unwrap(), "L68\nR48\n");
+        assert!(matches!(client.fetch_input(2), Err(ClientError::Status { status: 404, .. })));
+        assert!(matches!(Client::new(&server.url(), "wrong").fetch_input(1), Err(ClientError::Status { status: 400, .. })));
+    }
+
+    #[test]
+    fn downloads_once() {
+        let server = server();
+        let client = Client::new(&server.url(), SESSION);
+        let dir = temp_dir("download");
+        let resolver = InputResolver::in_dir(&dir);
+
+        let (path, downloaded) = client.download(&resolver, 1, "real", false).unwrap();
+        assert!(downloaded);
+        assert_eq!(path, dir.join("Inputs/2025_1.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "L68\nR48\n");
+
+        assert_eq!(client.download(&resolver, 1, "real", false).unwrap(), (path.clone(), false));
+        assert_eq!(client.download(&resolver, 1, "real", true).unwrap(), (path, true));
+        assert_eq!(server.requests("/2025/day/1/input"), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn submits_with_history() {
+        let server = server();
+        let client = Client::new(&server.url(), SESSION);
+        let mut history = SubmissionHistory::default();
+
+        let wrong = client.submit(&mut history, 1, 1, "5", 1000).unwrap();
+        assert_eq!((wrong.verdict, wrong.wait), (Verdict::TooHigh, 60));
+
+        // Refused locally, without reaching the server
+        assert!(matches!(client.submit(&mut history, 1, 1, "5", 2000), Err(ClientError::AlreadySubmitted { verdict: Verdict::TooHigh, .. })));
+        assert!(matches!(client.submit(&mut history, 1, 1, "3", 1030), Err(ClientError::TooSoon { wait }) if wait.as_secs() == 30));
+        assert_eq!(server.requests("/2025/day/1/answer"), 1);
+
+        // The server keeps its own clock, so it still thinks this is too soon
+        let early = client.submit(&mut history, 1, 1, "3", 1060).unwrap();
+        assert_eq!(early.verdict, Verdict::TooSoon);
+        assert!(early.wait > 0);
+
+        server.reset_wait();
+        let right = client.submit(&mut history, 1, 1, "3", 1060 + early.wait).unwrap();
+        assert_eq!(right.verdict, Verdict::Correct);
+        assert!(matches!(client.submit(&mut history, 1, 1, "4", 5000), Err(ClientError::AlreadySolved { .. })));
+        assert_eq!(history.submissions().len(), 3);
+    }
+
+    #[test]
+    fn history_round_trips_through_toml() {
+        let mut history = SubmissionHistory::default();
+        history.submissions.push(Submission { day: 3, part: 2, answer: "17".to_string(), verdict: Verdict::TooLow, submitted_at: 100, wait: 300 });
+
+        let dir = temp_dir("history");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(HISTORY_FILE);
+        history.save(&path).unwrap();
+        let loaded = SubmissionHistory::load(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded.submissions(), history.submissions());
+        assert_eq!(loaded.next_allowed(), 400);
+        assert_eq!(loaded.judged(3, 2, "17").map(|submission| submission.verdict), Some(Verdict::TooLow));
+    }
+
+    #[test]
+    fn parses_waits() {
+        assert_eq!(parse_wait("You have 1m 5s left to wait."), Some(65));
+        assert_eq!(parse_wait("Please wait one minute before trying again."), Some(60));
+        assert_eq!(parse_wait("please wait 5 minutes before trying again."), Some(300));
+        assert_eq!(parse_wait("That's the right answer!"), None);
+        // Lowercasing İ makes it longer, which mustn't shift where the wait is read from
+        assert_eq!(parse_wait("İSTANBUL İS CALLİNG. Please wait 5 minutes."), Some(300));
+        assert_eq!(parse_wait("You have 5é left to wait."), None);
+    }
+}
@@ -0,0 +1,213 @@
+//! A stand-in for the puzzle site, serving canned inputs and judging answers the way the
+//! real one words it, so the client can be tested offline.
+
+use crate::YEAR;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long the site makes you wait after a wrong answer.
+const WAIT: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+struct State {
+    inputs: HashMap<u32, String>,
+    answers: HashMap<(u32, u8), String>,
+    solved: Vec<(u32, u8)>,
+    blocked_until: Option<Instant>,
+    /// Paths requested so far, in order.
+    requests: Vec<String>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    cookie: Option<String>,
+    body: String,
+}
+
+pub struct MockServer {
+    url: String,
+    session: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl MockServer {
+    /// Starts serving on a free local port, accepting only `session` as a logged-in cookie.
+    pub fn start(session: &str) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = MockServer { url, session: session.to_string(), state: Arc::default() };
+
+        let state = Arc::clone(&server.state);
+        let session = server.session.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle(stream, &session, &state);
+            }
+        });
+        server
+    }
+
+    pub fn with_input(self, day: u32, input: &str) -> Self {
+        self.state.lock().unwrap().inputs.insert(day, input.to_string());
+        self
+    }
+
+    pub fn with_answer(self, day: u32, part: u8, answer: &str) -> Self {
+        self.state.lock().unwrap().answers.insert((day, part), answer.to_string());
+        self
+    }
+
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    /// How many times `path` has been requested.
+    pub fn requests(&self, path: &str) -> usize {
+        self.state.lock().unwrap().requests.iter().filter(|requested| *requested == path).count()
+    }
+
+    /// Lets the next answer through, as if the wait after a wrong one had passed.
+    pub fn reset_wait(&self) {
+        self.state.lock().unwrap().blocked_until = None;
+    }
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut request_line = line.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+
+    let mut cookie = None;
+    let mut length = 0;
+    loop {
+        line.clear();
+        reader.read_line(&mut line).ok()?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header.split_once(':')?;
+        match name.to_ascii_lowercase().as_str() {
+            "cookie" => cookie = value.trim().strip_prefix("session=").map(str::to_string),
+            "content-length" => length = value.trim().parse().ok()?,
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+    Some(Request { method, path, cookie, body: String::from_utf8(body).ok()? })
+}
+
+/// Decodes an `application/x-www-form-urlencoded` body.
+fn form(body: &str) -> HashMap<String, String> {
+    body.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| {
+            let value = value.replace('+', " ");
+            let mut decoded = Vec::new();
+            let mut bytes = value.bytes();
+            while let Some(byte) = bytes.next() {
+                if byte == b'%' {
+                    let hex: String = bytes.by_ref().take(2).map(char::from).collect();
+                    decoded.push(u8::from_str_radix(&hex, 16).unwrap_or(b'?'));
+                } else {
+                    decoded.push(byte);
+                }
+            }
+            (key.to_string(), String::from_utf8_lossy(&decoded).into_owned())
+        })
+        .collect()
+}
+
+fn page(article: &str) -> String {
+    format!("<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n", article)
+}
+
+fn judge(state: &mut State, day: u32, part: u8, answer: &str) -> String {
+    let now = Instant::now();
+    if let Some(left) = state.blocked_until.and_then(|until| until.checked_duration_since(now)) {
+        let left = left.as_secs().max(1);
+        return page(&format!(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {}m {}s left to wait.",
+            left / 60,
+            left % 60
+        ));
+    }
+
+    let expected = state.answers.get(&(day, part));
+    if expected.is_none() || state.solved.contains(&(day, part)) {
+        return page("You don't seem to be solving the right level.  Did you already complete it?");
+    }
+    if expected.map(String::as_str) == Some(answer) {
+        state.solved.push((day, part));
+        return page("That's the right answer!  You are one gold star closer to decorating the North Pole.");
+    }
+
+    state.blocked_until = Some(now + WAIT);
+    let hint = match (answer.parse::<i64>(), expected.unwrap().parse::<i64>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => "  If you're stuck, make sure you're using the full input data; your answer is too high.",
+        (Ok(answer), Ok(expected)) if answer < expected => "  If you're stuck, make sure you're using the full input data; your answer is too low.",
+        _ => "",
+    };
+    page(&format!("That's not the right answer.{}  Please wait one minute before trying again.", hint))
+}
+
+fn respond(request: &Request, session: &str, state: &mut State) -> (u16, String) {
+    state.requests.push(request.path.clone());
+    let logged_in = request.cookie.as_deref() == Some(session);
+
+    let prefix = format!("/{}/day/", YEAR);
+    let Some((day, endpoint)) = request.path.strip_prefix(&prefix).and_then(|rest| rest.split_once('/')) else {
+        return (404, "404 Not Found\n".to_string());
+    };
+    let Ok(day) = day.parse::<u32>() else {
+        return (404, "404 Not Found\n".to_string());
+    };
+
+    match (request.method.as_str(), endpoint) {
+        ("GET", "input") if !logged_in => (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".to_string()),
+        ("GET", "input") => match state.inputs.get(&day) {
+            Some(input) => (200, input.clone()),
+            None => (404, "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n".to_string()),
+        },
+        ("POST", "answer") if !logged_in => (400, "You must be logged in to submit answers.\n".to_string()),
+        ("POST", "answer") => {
+            let form = form(&request.body);
+            match (form.get("level").and_then(|level| level.parse().ok()), form.get("answer")) {
+                (Some(part), Some(answer)) => (200, judge(state, day, part, answer)),
+                _ => (400, "Bad Request\n".to_string()),
+            }
+        }
+        _ => (404, "404 Not Found\n".to_string()),
+    }
+}
+
+fn handle(mut stream: TcpStream, session: &str, state: &Mutex<State>) {
+    let Some(request) = read_request(&stream) else {
+        return;
+    };
+    let (status, body) = respond(&request, session, &mut state.lock().unwrap());
+
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        _ => "Not Found",
+    };
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    );
+}
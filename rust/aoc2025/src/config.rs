@@ -0,0 +1,95 @@
+//! `aoc.toml`, the project's config file.
+//!
+//! It's read once, and each part of the runner takes only its own table: the
+//! [`InputResolver`](crate::InputResolver) `[inputs]`, the [`Client`](crate::client::Client)
+//! `[client]` and the solvers the `[params.N]` overrides.
+
+use crate::client::ClientConfig;
+use crate::input::InputsConfig;
+use crate::params::ParamOverrides;
+use serde::Deserialize;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Project-level config file, looked up from the current directory upwards.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Directory holding `aoc.toml`, if there is one.
+    #[serde(skip)]
+    pub dir: Option<PathBuf>,
+    #[serde(default)]
+    pub inputs: InputsConfig,
+    #[serde(default)]
+    pub client: ClientConfig,
+    #[serde(default)]
+    pub params: ParamOverrides,
+}
+
+#[derive(Debug)]
+pub struct ConfigError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid {}: {}", self.path.display(), self.message)
+    }
+}
+
+impl Error for ConfigError {}
+
+impl Config {
+    /// The nearest `aoc.toml` from the current directory upwards, or the defaults if there
+    /// isn't one.
+    pub fn load() -> Result<Self, ConfigError> {
+        let cwd = env::current_dir().unwrap();
+        match cwd.ancestors().map(|dir| dir.join(CONFIG_FILE)).find(|path| path.is_file()) {
+            Some(path) => Config::from_file(&path),
+            None => Ok(Config::default()),
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let error = |message: String| ConfigError { path: path.to_path_buf(), message };
+        let text = fs::read_to_string(path).map_err(|err| error(err.to_string()))?;
+        let config: Config = toml::from_str(&text).map_err(|err| error(err.message().to_string()))?;
+        Ok(Config { dir: path.parent().map(Path::to_path_buf), ..config })
+    }
+
+    /// Directory holding `aoc.toml`, or the current directory if there isn't one.
+    pub fn project_dir(&self) -> PathBuf {
+        self.dir.clone().unwrap_or_else(|| env::current_dir().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_into_tables() {
+        let config: Config = toml::from_str(
+            "[inputs]\ndir = \"../Inputs\"\n\n[client]\nbase_url = \"http://localhost:8080\"\n\n[params.8]\nnum_pairs = 10\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.inputs.dir, Some(PathBuf::from("../Inputs")));
+        assert_eq!(config.client.base_url.as_deref(), Some("http://localhost:8080"));
+        assert_eq!(config.params.day(8).get("num_pairs").and_then(|value| value.as_integer()), Some(10));
+        assert!(config.params.day(11).is_empty());
+    }
+
+    #[test]
+    fn rejects_misspelled_tables() {
+        for text in ["[input]\ndir = \"../Inputs\"\n", "[param.8]\nnum_pairs = 10\n", "dir = \"../Inputs\"\n"] {
+            assert!(toml::from_str::<Config>(text).is_err(), "{:?}", text);
+        }
+    }
+}
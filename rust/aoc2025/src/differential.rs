@@ -0,0 +1,93 @@
+use crate::Puzzle;
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// A generated input the solver and reference solver disagree on. The input can be
+/// regenerated from `seed` and `size` with [`Puzzle::generate`].
+#[derive(Debug, Clone)]
+pub struct Disagreement {
+    pub day: u32,
+    pub part: u8,
+    pub seed: u64,
+    pub size: usize,
+    /// The solver's answer, or how it failed
+    pub answer: String,
+    /// The reference solver's answer, or how it failed
+    pub expected: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {} part {} disagrees with the reference on seed {} (size {}): got {}, expected {}",
+            self.day, self.part, self.seed, self.size, self.answer, self.expected
+        )
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+/// What running a solver on an input gave.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(String),
+    Error(String),
+    Panic(String),
+}
+
+impl Outcome {
+    pub fn same_kind(&self, other: &Outcome) -> bool {
+        matches!(
+            (self, other),
+            (Outcome::Answer(_), Outcome::Answer(_)) | (Outcome::Error(_), Outcome::Error(_)) | (Outcome::Panic(_), Outcome::Panic(_))
+        )
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Answer(answer) => f.write_str(answer),
+            Outcome::Error(err) => write!(f, "error ({})", err),
+            Outcome::Panic(message) => write!(f, "panic ({})", message),
+        }
+    }
+}
+
+/// Runs `part` of the solver and then of the reference solver on `contents`.
+pub fn run(puzzle: &Puzzle, contents: &str, part: u8) -> (Outcome, Outcome) {
+    let answer = match panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(contents, &[part]))) {
+        Ok(Ok(solved)) => Outcome::Answer(solved.parts[0].answer.to_string()),
+        Ok(Err(err)) => Outcome::Error(err.to_string()),
+        Err(payload) => Outcome::Panic(panic_message(payload)),
+    };
+    let expected = match panic::catch_unwind(AssertUnwindSafe(|| puzzle.reference(contents, part).to_string())) {
+        Ok(expected) => Outcome::Answer(expected),
+        Err(payload) => Outcome::Panic(panic_message(payload)),
+    };
+    (answer, expected)
+}
+
+/// Runs the solver and reference solver on `cases` inputs generated from seeds `seed..`,
+/// stopping at the first input where they give different answers, fail or panic.
+pub fn check(puzzle: &Puzzle, parts: &[u8], seed: u64, cases: u64, size: usize) -> Result<(), Disagreement> {
+    for seed in seed..seed + cases {
+        let contents = puzzle.generate(seed, size);
+
+        for &part in parts {
+            let (answer, expected) = run(puzzle, &contents, part);
+            if answer != expected {
+                return Err(Disagreement { day: puzzle.day, part, seed, size, answer: answer.to_string(), expected: expected.to_string() });
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! Inputs built into the binaries, for machines without the input files.
+//!
+//! With the `embed` feature, the build script includes each day's `{year}_{day}.txt` from
+//! `$AOC_EMBED_DIR` (relative to the workspace), the `[inputs]` dir of `aoc.toml` or
+//! `../../Inputs`, in that order. Their default input is then always the embedded one, so
+//! solving it doesn't look at the filesystem at all.
+//!
+//! ```text
+//! AOC_EMBED_DIR=/path/to/Inputs cargo build --release -p aoc --features embed
+//! ```
+
+use crate::input::{Input, InputError, DEFAULT_NAME};
+use crate::YEAR;
+use std::io;
+use std::path::PathBuf;
+
+include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+
+/// Whether inputs were embedded, in which case they're used instead of looking for files.
+pub const ENABLED: bool = cfg!(feature = "embed");
+
+/// Days with an embedded input.
+pub fn days() -> impl Iterator<Item = u32> {
+    INPUTS.iter().map(|(day, _)| *day)
+}
+
+/// The embedded input for `day`.
+pub fn input(day: u32) -> Result<Input, InputError> {
+    let file_name = format!("{}_{}.txt", YEAR, day);
+    let Some((_, bytes)) = INPUTS.iter().find(|(embedded, _)| *embedded == day) else {
+        let tried = vec![(PathBuf::from(DIR).join(&file_name), "embedded at build time")];
+        return Err(InputError::NotFound { day, name: DEFAULT_NAME.to_string(), tried });
+    };
+
+    let path = format!("<embedded {}>", file_name);
+    match String::from_utf8(bytes.to_vec()) {
+        Ok(contents) => Ok(Input::new(path, contents)),
+        Err(err) => Err(InputError::Read { path, source: io::Error::new(io::ErrorKind::InvalidData, err) }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embeds_only_puzzle_days() {
+        assert!(days().all(|day| (1..=12).contains(&day)));
+        assert!(matches!(input(13), Err(InputError::NotFound { day: 13, .. })));
+    }
+}
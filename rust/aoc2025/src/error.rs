@@ -0,0 +1,110 @@
+use crate::num::Overflow;
+use crate::params::ParamsError;
+use crate::parse::{self, Span};
+use crate::ParseError;
+use std::error::Error;
+use std::fmt;
+
+/// Why a part couldn't be solved: malformed input, an answer too big for [`Int`](crate::Int),
+/// well-formed input beyond what the solver handles, or parameter overrides the day doesn't take.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Overflow(Overflow),
+    Unsupported(Unsupported),
+    Params(ParamsError),
+}
+
+/// Well-formed input the solver gives up on, like a region too big to search, so unlike
+/// a [`ParseError`] there's nothing to fix in the input. Made with [`Span::unsupported`];
+/// `day` and `part` are filled in by [`Puzzle::solve`](crate::Puzzle::solve).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unsupported {
+    pub day: u32,
+    pub part: u8,
+    pub span: Span,
+    pub reason: String,
+}
+
+impl Unsupported {
+    pub fn on_part(mut self, day: u32, part: u8) -> Self {
+        self.day = day;
+        self.part = part;
+        self
+    }
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {} part {}, line {}, column {}: {}", self.day, self.part, self.span.line, self.span.column, self.reason)
+    }
+}
+
+impl Error for Unsupported {}
+
+impl SolveError {
+    pub fn on_part(self, day: u32, part: u8) -> Self {
+        match self {
+            SolveError::Parse(err) => SolveError::Parse(err.on_day(day)),
+            SolveError::Overflow(overflow) => SolveError::Overflow(overflow.on_part(day, part)),
+            SolveError::Unsupported(err) => SolveError::Unsupported(err.on_part(day, part)),
+            SolveError::Params(err) => SolveError::Params(err),
+        }
+    }
+
+    /// Multi-line diagnostic for printing to a terminal, quoting `contents` for malformed input.
+    pub fn render(&self, path: &str, contents: &str) -> String {
+        match self {
+            SolveError::Parse(err) => err.render(path, contents),
+            SolveError::Overflow(overflow) => format!("error: {}\n --> {}", overflow, path),
+            SolveError::Unsupported(err) => parse::quote(err, path, contents, err.span.line, err.span.column, &err.span.text),
+            SolveError::Params(err) => format!("error: {}", err),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Parse(err) => err.fmt(f),
+            SolveError::Overflow(overflow) => overflow.fmt(f),
+            SolveError::Unsupported(err) => err.fmt(f),
+            SolveError::Params(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::Parse(err) => Some(err),
+            SolveError::Overflow(overflow) => Some(overflow),
+            SolveError::Unsupported(err) => Some(err),
+            SolveError::Params(err) => Some(err),
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+impl From<Overflow> for SolveError {
+    fn from(overflow: Overflow) -> Self {
+        SolveError::Overflow(overflow)
+    }
+}
+
+impl From<Unsupported> for SolveError {
+    fn from(err: Unsupported) -> Self {
+        SolveError::Unsupported(err)
+    }
+}
+
+impl From<ParamsError> for SolveError {
+    fn from(err: ParamsError) -> Self {
+        SolveError::Params(err)
+    }
+}
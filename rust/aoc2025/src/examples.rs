@@ -0,0 +1,48 @@
+use crate::normalize::normalize;
+use crate::{Puzzle, Solution};
+
+/// A worked example from a puzzle's description, with the answers it gives.
+///
+/// Days list theirs in [`Solution::EXAMPLES`], usually with `include_str!` from the
+/// crate's `fixtures` directory. Either answer can be left out, e.g. when part 2
+/// has its own example.
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub fn answer(&self, part: u8) -> Option<&'static str> {
+        match part {
+            1 => self.part1,
+            2 => self.part2,
+            _ => panic!("Unknown part: {}", part),
+        }
+    }
+}
+
+/// Runs `part` on every example of `S` with an answer for it, panicking on the first
+/// that's malformed or gives a different answer. Meant to be called from each day's tests.
+pub fn check<S: Solution>(part: u8) {
+    let puzzle = Puzzle::of::<S>();
+
+    let mut checked = 0;
+    for example in S::EXAMPLES {
+        let Some(expected) = example.answer(part) else {
+            continue;
+        };
+
+        // Fixtures checked out with CRLF line endings should still pass
+        let (input, _) = normalize(example.input);
+        let solved = puzzle
+            .solve(&input, &[part])
+            .unwrap_or_else(|err| panic!("{}", err.render(example.name, &input)));
+        assert_eq!(solved.parts[0].answer.to_string(), expected, "Day {} part {} on example '{}'", S::DAY, part, example.name);
+        checked += 1;
+    }
+
+    assert!(checked > 0, "Day {} has no examples for part {}", S::DAY, part);
+}
@@ -0,0 +1,85 @@
+use std::ops::RangeInclusive;
+
+/// Small seedable PRNG (SplitMix64) for generating inputs.
+///
+/// Not suitable for anything but tests, but the same seed gives the same input on every
+/// platform, which is what matters when reporting a failing case.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Empty range");
+        // Rejection sampling, so every value is equally likely
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+
+    /// Uniform in `range`.
+    pub fn int(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range");
+        let span = end.wrapping_sub(start) as u64;
+        if span == u64::MAX {
+            return self.next_u64() as i64;
+        }
+        start.wrapping_add(self.below(span + 1) as i64)
+    }
+
+    /// Uniform index into something of length `len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for ix in (1..items.len()).rev() {
+            items.swap(ix, self.index(ix + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_values() {
+        let values = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.int(-3..=3)).collect::<Vec<i64>>()
+        };
+
+        assert_eq!(values(7), values(7));
+        assert_ne!(values(7), values(8));
+        assert!(values(7).iter().all(|v| (-3..=3).contains(v)));
+    }
+}
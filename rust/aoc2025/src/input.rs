@@ -0,0 +1,248 @@
+use crate::config::CONFIG_FILE;
+use crate::embedded;
+use crate::normalize::{normalize, Change};
+use crate::YEAR;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable pointing at a directory of inputs, checked before the config file.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+/// Name of the input used when none is given.
+pub const DEFAULT_NAME: &str = "real";
+
+/// The `[inputs]` table of `aoc.toml`.
+///
+/// ```toml
+/// [inputs]
+/// dir = "../Inputs"                      # relative to aoc.toml
+///
+/// [inputs.names]
+/// example = "examples/{year}_{day}.txt"  # relative to dir
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InputsConfig {
+    pub dir: Option<PathBuf>,
+    #[serde(default)]
+    pub names: HashMap<String, String>,
+}
+
+/// Puzzle input text, along with where it came from.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub path: String,
+    /// The text as read, after [`normalize`].
+    pub contents: String,
+    /// What [`normalize`] had to change, for warning about.
+    pub changes: Vec<Change>,
+}
+
+impl Input {
+    pub fn new(path: String, contents: String) -> Self {
+        let (normalized, changes) = normalize(&contents);
+        let contents = match normalized {
+            Cow::Borrowed(_) => contents,
+            Cow::Owned(normalized) => normalized,
+        };
+        Input { path, contents, changes }
+    }
+
+    /// One line per change made to the input, e.g. to print before solving it.
+    pub fn warnings(&self) -> impl Iterator<Item = String> + '_ {
+        self.changes.iter().map(|change| format!("warning: {}: {}", self.path, change))
+    }
+
+    /// Hex SHA-256 of the contents, identifying the input independently of where it's stored
+    /// and, since they're normalized, of how it was saved.
+    pub fn hash(&self) -> String {
+        hash(&self.contents)
+    }
+}
+
+pub fn hash(contents: &str) -> String {
+    Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// No candidate location had the input. `tried` pairs each path with why it was tried.
+    NotFound { day: u32, name: String, tried: Vec<(PathBuf, &'static str)> },
+    Read { path: String, source: io::Error },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, name, tried } => {
+                write!(f, "No '{}' input for day {}. Looked in:", name, day)?;
+                for (path, reason) in tried {
+                    write!(f, "\n  {} ({})", path.display(), reason)?;
+                }
+                Ok(())
+            }
+            InputError::Read { path, source } => write!(f, "Could not read {}: {}", path, source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Read { source, .. } => Some(source),
+            InputError::NotFound { .. } => None,
+        }
+    }
+}
+
+/// Finds puzzle inputs, trying in order:
+///
+/// 1. `$AOC_INPUT_DIR`
+/// 2. the `[inputs]` dir of the nearest `aoc.toml`
+/// 3. `../../../Inputs` when run from a day's directory, then `./Inputs`
+#[derive(Debug, Clone)]
+pub struct InputResolver {
+    cwd: PathBuf,
+    env_dir: Option<PathBuf>,
+    config_dir: Option<PathBuf>,
+    config: InputsConfig,
+}
+
+impl InputResolver {
+    /// Resolver for the current directory and environment, with the `[inputs]` table of the
+    /// `aoc.toml` in `config_dir`.
+    pub fn new(config_dir: Option<PathBuf>, config: InputsConfig) -> Self {
+        let cwd = env::current_dir().unwrap();
+        let env_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+        InputResolver { cwd, env_dir, config_dir, config }
+    }
+
+    /// Resolver for `dir`, ignoring the environment and any `aoc.toml`.
+    #[cfg(test)]
+    pub(crate) fn in_dir(dir: &Path) -> Self {
+        InputResolver { cwd: dir.to_path_buf(), env_dir: None, config_dir: None, config: InputsConfig::default() }
+    }
+
+    /// File name of input `name` for `day`, relative to an input directory.
+    pub fn file_name(&self, day: u32, name: &str) -> String {
+        let pattern = match self.config.names.get(name) {
+            Some(pattern) => pattern.as_str(),
+            None if name == DEFAULT_NAME => "{year}_{day}.txt",
+            None => "{year}_{day}_{name}.txt",
+        };
+
+        pattern
+            .replace("{year}", &YEAR.to_string())
+            .replace("{day}", &day.to_string())
+            .replace("{name}", name)
+    }
+
+    /// Every location input `name` for `day` could be in, in the order they're tried.
+    pub fn candidates(&self, day: u32, name: &str) -> Vec<(PathBuf, &'static str)> {
+        let file_name = self.file_name(day, name);
+        let mut candidates = Vec::new();
+
+        if let Some(dir) = &self.env_dir {
+            candidates.push((dir.join(&file_name), INPUT_DIR_VAR));
+        }
+        if let (Some(config_dir), Some(dir)) = (&self.config_dir, &self.config.dir) {
+            candidates.push((config_dir.join(dir).join(&file_name), CONFIG_FILE));
+        }
+        if self.cwd.ends_with(day.to_string()) {
+            candidates.push((self.cwd.join("../../../Inputs").join(&file_name), "day directory"));
+        }
+        candidates.push((self.cwd.join("Inputs").join(&file_name), "current directory"));
+
+        candidates
+    }
+
+    /// Where a downloaded input `name` for `day` belongs: the first of its candidates.
+    pub fn destination(&self, day: u32, name: &str) -> PathBuf {
+        self.candidates(day, name).swap_remove(0).0
+    }
+
+    pub fn resolve(&self, day: u32, name: &str) -> Result<PathBuf, InputError> {
+        let tried = self.candidates(day, name);
+        match tried.iter().find(|(path, _)| path.is_file()) {
+            Some((path, _)) => Ok(path.clone()),
+            None => Err(InputError::NotFound { day, name: name.to_string(), tried }),
+        }
+    }
+
+    /// Reads `path` if given (`-` for stdin), otherwise the resolved input `name` for `day`,
+    /// or the embedded one if this build has [`embedded`] inputs and `name` is the default.
+    pub fn read(&self, day: u32, name: &str, path: Option<&str>) -> Result<Input, InputError> {
+        match path {
+            Some("-") => {
+                let mut contents = String::new();
+                io::stdin()
+                    .read_to_string(&mut contents)
+                    .map_err(|source| InputError::Read { path: "<stdin>".to_string(), source })?;
+                Ok(Input::new("<stdin>".to_string(), contents))
+            }
+            Some(path) => read_file(Path::new(path)),
+            None if embedded::ENABLED && name == DEFAULT_NAME => embedded::input(day),
+            None => read_file(&self.resolve(day, name)?),
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<Input, InputError> {
+    let display = path.display().to_string();
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Input::new(display, contents)),
+        Err(source) => Err(InputError::Read { path: display, source }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolver(cwd: &str) -> InputResolver {
+        InputResolver {
+            cwd: PathBuf::from(cwd),
+            env_dir: Some(PathBuf::from("/env")),
+            config_dir: Some(PathBuf::from("/project")),
+            config: InputsConfig {
+                dir: Some(PathBuf::from("inputs")),
+                names: HashMap::from([("example".to_string(), "examples/{day}.txt".to_string())]),
+            },
+        }
+    }
+
+    #[test]
+    fn candidates_in_priority_order() {
+        let candidates: Vec<PathBuf> = resolver("/project/rust/7").candidates(7, "real").into_iter().map(|(path, _)| path).collect();
+
+        assert_eq!(candidates, vec![
+            PathBuf::from("/env/2025_7.txt"),
+            PathBuf::from("/project/inputs/2025_7.txt"),
+            PathBuf::from("/project/rust/7/../../../Inputs/2025_7.txt"),
+            PathBuf::from("/project/rust/7/Inputs/2025_7.txt"),
+        ]);
+    }
+
+    #[test]
+    fn hashes_contents() {
+        assert_eq!(hash("abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+
+    #[test]
+    fn named_inputs() {
+        let resolver = resolver("/project");
+
+        assert_eq!(resolver.file_name(3, "example"), "examples/3.txt");
+        assert_eq!(resolver.file_name(3, "alt-account"), "2025_3_alt-account.txt");
+    }
+}
@@ -0,0 +1,124 @@
+use serde::de::DeserializeOwned;
+use std::env;
+use std::process;
+
+mod answer;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod config;
+pub mod differential;
+pub mod embedded;
+mod error;
+pub mod examples;
+pub mod generate;
+pub mod input;
+pub mod memo;
+pub mod minimize;
+pub mod normalize;
+pub mod num;
+pub mod params;
+pub mod parse;
+mod puzzle;
+
+pub use answer::Answer;
+pub use answers::AnswerStore;
+pub use config::Config;
+pub use error::{SolveError, Unsupported};
+pub use examples::Example;
+pub use generate::Rng;
+pub use input::{Input, InputError, InputResolver};
+pub use minimize::Records;
+pub use num::{Int, Overflow};
+pub use parse::ParseError;
+pub use puzzle::{Parsed, PartResult, Puzzle, Solved};
+
+pub const YEAR: u32 = 2025;
+
+/// A single day's puzzle, split into parsing and the two parts.
+///
+/// Each `rust/N` crate implements this for its day so the solvers can be
+/// linked against directly instead of scraping the binaries' output. `parse`
+/// builds the day's model once and both parts borrow it. Parts whose answers
+/// can outgrow `i64` compute them as [`Int`] with the checked [`num`] helpers.
+pub trait Solution {
+    const DAY: u32;
+    const TITLE: &'static str;
+    /// Labels printed next to the part 1 and part 2 answers.
+    const LABELS: [&'static str; 2];
+    /// Examples from the puzzle description, checked by the day's tests.
+    const EXAMPLES: &'static [Example];
+    /// What the input is a list of, for [`minimize`] to delete. `None` if it can't be
+    /// shrunk that way, like a grid.
+    const RECORDS: Option<Records> = None;
+
+    /// The parsed model. It owns its data and can be shared between threads, so it can
+    /// outlive the contents it was parsed from and be solved away from where it was parsed.
+    type Input: Send + Sync + 'static;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+    /// Constants the solver uses, defaulting to the puzzle's own values. Days with
+    /// nothing to tune use [`NoParams`](params::NoParams).
+    type Params: Default + DeserializeOwned;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError>;
+
+    /// `parse`, keeping `params` in the model for the parts to use. Days with params
+    /// override this and have `parse` call it with the defaults.
+    fn parse_with(contents: &str, _params: &Self::Params) -> Result<Self::Input, ParseError> {
+        Self::parse(contents)
+    }
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2, SolveError>;
+
+    /// Every place `contents` breaks an assumption the solver makes without checking,
+    /// or only checks for the first time it's broken. Lines `parse` would reject are
+    /// skipped, since it reports them itself.
+    fn lint(_contents: &str) -> Vec<ParseError> {
+        Vec::new()
+    }
+
+    /// Deliberately naive solutions, to check `part1` and `part2` against. They can
+    /// assume well-formed input and may panic on anything else.
+    fn reference1(contents: &str) -> Self::Part1;
+    fn reference2(contents: &str) -> Self::Part2;
+
+    /// Random input in the puzzle's format, roughly `size` items (lines, ranges, machines...) big.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Entry point shared by every day's binary. Takes an optional input path (`-` for stdin).
+pub fn main<S: Solution>() {
+    let args: Vec<String> = env::args().collect();
+
+    // An embedded input doesn't need the current directory or aoc.toml to find it
+    let input = match args.get(1) {
+        None if embedded::ENABLED => embedded::input(S::DAY).map_err(|err| err.to_string()),
+        path => Config::load().map_err(|err| err.to_string()).and_then(|config| {
+            InputResolver::new(config.dir, config.inputs)
+                .read(S::DAY, input::DEFAULT_NAME, path.map(String::as_str))
+                .map_err(|err| err.to_string())
+        }),
+    };
+    let input = input.unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+    for warning in input.warnings() {
+        eprintln!("{}", warning);
+    }
+    let Input { path, contents, .. } = input;
+
+    match Puzzle::of::<S>().solve(&contents, &[1, 2]) {
+        Ok(solved) => {
+            println!("Parsed in {:.5?}", solved.parse);
+            for result in solved.parts {
+                println!("{}", result);
+            }
+        }
+        Err(err) => {
+            eprintln!("{}", err.render(&path, &contents));
+            process::exit(1);
+        }
+    }
+}
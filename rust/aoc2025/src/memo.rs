@@ -0,0 +1,189 @@
+//! Memoization scoped to a single run of a part.
+//!
+//! A [`Memo`] is created by the part that needs it and passed down explicitly, so its
+//! entries are dropped with it instead of piling up across runs, and benchmarks time
+//! real work rather than hits left over from the previous iteration. When a memo is
+//! dropped its statistics are recorded for the current thread, and
+//! [`Puzzle::solve`](crate::Puzzle::solve) collects them into each [`PartResult`](crate::PartResult).
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// Hits and misses of the memos with one name, over one run of a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemoStats {
+    pub name: &'static str,
+    pub hits: u64,
+    pub misses: u64,
+    /// Times a memo hit its limit and was cleared.
+    pub resets: u64,
+    /// Most entries any one of the memos held at once.
+    pub peak: usize,
+}
+
+impl MemoStats {
+    fn new(name: &'static str) -> Self {
+        MemoStats { name, hits: 0, misses: 0, resets: 0, peak: 0 }
+    }
+
+    fn merge(&mut self, other: &MemoStats) {
+        self.hits += other.hits;
+        self.misses += other.misses;
+        self.resets += other.resets;
+        self.peak = self.peak.max(other.peak);
+    }
+
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} hits, {} misses ({:.1}% hit rate), peak {} entries",
+            self.name,
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.peak
+        )?;
+        if self.resets > 0 {
+            write!(f, ", reset {} times", self.resets)?;
+        }
+        Ok(())
+    }
+}
+
+thread_local! {
+    static RECORDED: RefCell<Vec<MemoStats>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(stats: &MemoStats) {
+    RECORDED.with(|recorded| {
+        let mut recorded = recorded.borrow_mut();
+        match recorded.iter_mut().find(|recorded| recorded.name == stats.name) {
+            Some(existing) => existing.merge(stats),
+            None => recorded.push(stats.clone()),
+        }
+    });
+}
+
+/// Statistics of every memo dropped on this thread since the last call, merged by name.
+pub fn take_stats() -> Vec<MemoStats> {
+    RECORDED.with(|recorded| recorded.take())
+}
+
+/// A memo table for one run of a recursive function.
+pub struct Memo<K, V> {
+    entries: HashMap<K, V>,
+    limit: Option<usize>,
+    stats: MemoStats,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    /// An unbounded memo, reported under `name`.
+    pub fn new(name: &'static str) -> Self {
+        Memo { entries: HashMap::new(), limit: None, stats: MemoStats::new(name) }
+    }
+
+    /// Caps the memo at `limit` entries. A full memo is cleared before the next insert,
+    /// which is crude but keeps memory bounded without tracking recency.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// The value memoized for `key`, counting a hit or a miss.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.entries.get(key).cloned();
+        match value {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        value
+    }
+
+    /// Memoizes `value` for `key` and hands it back.
+    pub fn insert(&mut self, key: K, value: V) -> V {
+        if self.limit.is_some_and(|limit| self.entries.len() >= limit) {
+            self.entries.clear();
+            self.stats.resets += 1;
+        }
+        self.entries.insert(key, value.clone());
+        self.stats.peak = self.stats.peak.max(self.entries.len());
+        value
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn stats(&self) -> &MemoStats {
+        &self.stats
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        record(&self.stats);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        if let Some(value) = memo.get(&n) {
+            return value;
+        }
+        let value = fib(n - 1, memo) + fib(n - 2, memo);
+        memo.insert(n, value)
+    }
+
+    #[test]
+    fn counts_hits_and_misses() {
+        take_stats();
+        {
+            let mut memo = Memo::new("fib");
+            assert_eq!(fib(30, &mut memo), 832040);
+            assert_eq!(memo.len(), 29);
+        }
+        {
+            let mut memo = Memo::new("fib");
+            fib(10, &mut memo);
+        }
+
+        let stats = take_stats();
+        assert_eq!(stats.len(), 1);
+        assert_eq!((stats[0].hits, stats[0].misses, stats[0].peak), (27 + 7, 29 + 9, 29));
+        assert!(take_stats().is_empty());
+    }
+
+    #[test]
+    fn limit_bounds_entries() {
+        let mut memo = Memo::new("fib").with_limit(10);
+        assert_eq!(fib(30, &mut memo), 832040);
+        assert!(memo.len() <= 10);
+        assert_eq!(memo.stats().peak, 10);
+        assert!(memo.stats().resets > 0);
+    }
+}
@@ -0,0 +1,256 @@
+//! Shrinking an input that makes a solver fail down to a small reproducer.
+//!
+//! Each day says what its input is a list of with [`Solution::RECORDS`](crate::Solution::RECORDS),
+//! and [`minimize`] deletes records (delta debugging, halves first and then ever smaller
+//! chunks) for as long as the failure still reproduces.
+
+use crate::differential::{self, Outcome};
+use crate::Puzzle;
+use std::fmt;
+
+/// What a day's input is a list of, for deleting them one at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Records {
+    /// Every non-blank line. Blank lines separating sections are kept.
+    Lines,
+    /// Items on a single line, separated by the given character.
+    Separated(char),
+    /// Blocks of columns separated by a column of spaces in every row, like a worksheet.
+    Columns,
+    /// Each `to` of `from: to to ...` lines, so a graph loses one edge at a time.
+    Edges,
+    /// The lines of the last of the blank line separated sections. The others are kept as they are.
+    LastSection,
+}
+
+impl Records {
+    /// The records of `contents`, in order.
+    pub fn split(self, contents: &str) -> Vec<String> {
+        match self {
+            Records::Lines => contents.lines().filter(|line| !line.trim().is_empty()).map(String::from).collect(),
+            Records::Separated(separator) => contents.trim().split(separator).map(String::from).collect(),
+            Records::Columns => {
+                let rows: Vec<&str> = contents.lines().collect();
+                column_blocks(&rows).into_iter().map(|(start, end)| column(&rows, start, end).join("\n")).collect()
+            }
+            Records::Edges => contents
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .flat_map(|(from, to)| to.split_whitespace().map(move |to| format!("{}: {}", from, to)))
+                .collect(),
+            Records::LastSection => last_section(contents).1.lines().map(String::from).collect(),
+        }
+    }
+
+    /// `contents` with only the records where `keep` is true.
+    pub fn rebuild(self, contents: &str, keep: &[bool]) -> String {
+        let mut keep = keep.iter().copied();
+        let mut out = String::new();
+        match self {
+            Records::Lines => {
+                for line in contents.lines() {
+                    if line.trim().is_empty() || keep.next().unwrap_or(true) {
+                        out.push_str(line);
+                        out.push('\n');
+                    }
+                }
+            }
+            Records::Separated(separator) => {
+                let kept: Vec<&str> = contents.trim().split(separator).filter(|_| keep.next().unwrap_or(true)).collect();
+                out = kept.join(&separator.to_string());
+                out.push('\n');
+            }
+            Records::Columns => {
+                let rows: Vec<&str> = contents.lines().collect();
+                let kept: Vec<Vec<String>> = column_blocks(&rows)
+                    .into_iter()
+                    .filter(|_| keep.next().unwrap_or(true))
+                    .map(|(start, end)| column(&rows, start, end))
+                    .collect();
+                for row in 0..rows.len() {
+                    let cells: Vec<&str> = kept.iter().map(|block| block[row].as_str()).collect();
+                    out.push_str(&cells.join(" "));
+                    out.push('\n');
+                }
+            }
+            Records::Edges => {
+                for (from, to) in contents.lines().filter_map(|line| line.split_once(':')) {
+                    let kept: Vec<&str> = to.split_whitespace().filter(|_| keep.next().unwrap_or(true)).collect();
+                    if !kept.is_empty() {
+                        out.push_str(&format!("{}: {}\n", from, kept.join(" ")));
+                    }
+                }
+            }
+            Records::LastSection => {
+                let (head, last) = last_section(contents);
+                out.push_str(head);
+                for line in last.lines().filter(|_| keep.next().unwrap_or(true)) {
+                    out.push_str(line);
+                    out.push('\n');
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Everything up to and including the separator before the last section, and the last section.
+fn last_section(contents: &str) -> (&str, &str) {
+    match contents.trim_end().rfind("\n\n") {
+        Some(ix) => contents.split_at(ix + 2),
+        None => ("", contents),
+    }
+}
+
+/// Column ranges holding something in at least one row, split at columns that are blank in all of them.
+fn column_blocks(rows: &[&str]) -> Vec<(usize, usize)> {
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let blank = |col: usize| rows.iter().all(|row| row.as_bytes().get(col).is_none_or(|&c| c == b' '));
+
+    let mut blocks = Vec::new();
+    let mut start: Option<usize> = None;
+    for col in 0..=width {
+        match (start, col == width || blank(col)) {
+            (Some(from), true) => {
+                blocks.push((from, col));
+                start = None;
+            }
+            (None, false) => start = Some(col),
+            _ => {}
+        }
+    }
+    blocks
+}
+
+/// Columns `start..end` of every row, padded with spaces where a row is short.
+fn column(rows: &[&str], start: usize, end: usize) -> Vec<String> {
+    rows.iter()
+        .map(|row| format!("{:width$}", row.get(start..end.min(row.len())).unwrap_or(""), width = end - start))
+        .collect()
+}
+
+/// The smallest set of records `fails` still holds for that deleting chunks could find.
+/// Only ever deletes, so the result keeps the records' order.
+pub fn ddmin(records: usize, mut fails: impl FnMut(&[bool]) -> bool) -> Vec<bool> {
+    let mut kept: Vec<usize> = (0..records).collect();
+    let mut chunks = 2;
+    while kept.len() >= 2 {
+        let chunk_len = kept.len().div_ceil(chunks);
+        let mut reduced = false;
+        for start in (0..kept.len()).step_by(chunk_len) {
+            let rest: Vec<usize> = kept.iter().enumerate()
+                .filter(|&(ix, _)| ix < start || ix >= start + chunk_len)
+                .map(|(_, &record)| record)
+                .collect();
+            let mut keep = vec![false; records];
+            rest.iter().for_each(|&record| keep[record] = true);
+            if fails(&keep) {
+                kept = rest;
+                chunks = (chunks - 1).max(2);
+                reduced = true;
+                break;
+            }
+        }
+
+        if !reduced {
+            if chunk_len == 1 {
+                break;
+            }
+            chunks = (chunks * 2).min(kept.len());
+        }
+    }
+
+    let mut keep = vec![false; records];
+    kept.iter().for_each(|&record| keep[record] = true);
+    keep
+}
+
+/// A reproducer found by [`minimize`], with what the solver and reference solver give on it.
+#[derive(Debug, Clone)]
+pub struct Minimized {
+    pub contents: String,
+    pub records: usize,
+    pub original_records: usize,
+    pub answer: Outcome,
+    pub expected: Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MinimizeError {
+    /// The day has no [`Records`] to delete.
+    NoRecords { day: u32 },
+    /// The solver and reference solver already agree on the input.
+    Agrees { day: u32, part: u8, answer: String },
+}
+
+impl fmt::Display for MinimizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MinimizeError::NoRecords { day } => write!(f, "Day {} doesn't say what its input's records are", day),
+            MinimizeError::Agrees { day, part, answer } => {
+                write!(f, "Day {} part {} agrees with the reference on this input ({}), so there's nothing to minimize", day, part, answer)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MinimizeError {}
+
+/// Shrinks `contents` while `part` keeps failing the same way: the solver panicking,
+/// erroring or giving an answer that isn't the reference's, which mustn't panic itself.
+pub fn minimize(puzzle: &Puzzle, part: u8, contents: &str) -> Result<Minimized, MinimizeError> {
+    let records = puzzle.records.ok_or(MinimizeError::NoRecords { day: puzzle.day })?;
+    let (answer, expected) = differential::run(puzzle, contents, part);
+    if answer == expected {
+        return Err(MinimizeError::Agrees { day: puzzle.day, part, answer: answer.to_string() });
+    }
+
+    let original_records = records.split(contents).len();
+    let keep = ddmin(original_records, |keep| {
+        let (candidate_answer, candidate_expected) = differential::run(puzzle, &records.rebuild(contents, keep), part);
+        candidate_answer != candidate_expected
+            && candidate_answer.same_kind(&answer)
+            && !matches!(candidate_expected, Outcome::Panic(_))
+    });
+
+    let contents = records.rebuild(contents, &keep);
+    let (answer, expected) = differential::run(puzzle, &contents, part);
+    Ok(Minimized { contents, records: keep.iter().filter(|&&kept| kept).count(), original_records, answer, expected })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(records: Records, contents: &str, expected: &[&str]) {
+        let split = records.split(contents);
+        assert_eq!(split, expected);
+        assert_eq!(records.rebuild(contents, &vec![true; split.len()]), contents);
+    }
+
+    #[test]
+    fn splits_and_rebuilds() {
+        roundtrip(Records::Lines, "3-5\n10-14\n\n1\n5\n", &["3-5", "10-14", "1", "5"]);
+        roundtrip(Records::Separated(','), "11-22,95-115\n", &["11-22", "95-115"]);
+        roundtrip(Records::Columns, "123 328\n 45 64 \n*   +  \n", &["123\n 45\n*  ", "328\n64 \n+  "]);
+        roundtrip(Records::Edges, "aaa: you hhh\nyou: out\n", &["aaa: you", "aaa: hhh", "you: out"]);
+        roundtrip(Records::LastSection, "0:\n#\n\n1x1: 1\n2x2: 0\n", &["1x1: 1", "2x2: 0"]);
+    }
+
+    #[test]
+    fn rebuilds_without_records() {
+        assert_eq!(Records::Lines.rebuild("1\n\n2\n3\n", &[true, false, true]), "1\n\n3\n");
+        assert_eq!(Records::Separated(',').rebuild("1-2,3-4,5-6\n", &[false, true, true]), "3-4,5-6\n");
+        assert_eq!(Records::Columns.rebuild("1 22 3\n+ *  +\n", &[true, false, true]), "1 3\n+ +\n");
+        assert_eq!(Records::Edges.rebuild("a: b c\nb: c\n", &[false, true, false]), "a: c\n");
+        assert_eq!(Records::LastSection.rebuild("0:\n#\n\n1x1: 1\n2x2: 0\n", &[false, true]), "0:\n#\n\n2x2: 0\n");
+    }
+
+    #[test]
+    fn finds_smallest_failing_subset() {
+        // Fails whenever records 3 and 11 are both kept
+        let keep = ddmin(20, |keep| keep[3] && keep[11]);
+        let kept: Vec<usize> = (0..20).filter(|&ix| keep[ix]).collect();
+        assert_eq!(kept, vec![3, 11]);
+    }
+}
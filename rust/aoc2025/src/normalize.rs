@@ -0,0 +1,103 @@
+//! Cleaning up how an input was saved before it's parsed.
+//!
+//! The parsers assume `\n` line endings and no blank lines after the last line, and some
+//! split on `"\n\n"` or count lines, so an input saved on Windows, with a BOM or with
+//! extra newlines at the end breaks them in confusing ways. Trailing spaces within
+//! lines are left alone, since day 6 lines its columns up with them.
+
+use std::borrow::Cow;
+use std::fmt;
+
+/// Something [`normalize`] had to change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// Removed a UTF-8 byte order mark.
+    Bom,
+    /// Replaced `lines` CRLF line endings with LF.
+    CrLf { lines: usize },
+    /// Removed `lines` blank or whitespace-only lines from the end.
+    TrailingBlankLines { lines: usize },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Bom => write!(f, "removed a UTF-8 byte order mark"),
+            Change::CrLf { lines } => write!(f, "converted {} CRLF line endings to LF", lines),
+            Change::TrailingBlankLines { lines: 1 } => write!(f, "removed a blank line from the end"),
+            Change::TrailingBlankLines { lines } => write!(f, "removed {} blank lines from the end", lines),
+        }
+    }
+}
+
+/// `contents` without a BOM, with LF line endings and no blank lines after the last
+/// line, along with what had to change. Borrows when nothing did.
+pub fn normalize(contents: &str) -> (Cow<'_, str>, Vec<Change>) {
+    let mut changes = Vec::new();
+    let mut normalized = Cow::Borrowed(contents);
+
+    if let Some(rest) = contents.strip_prefix('\u{feff}') {
+        normalized = Cow::Borrowed(rest);
+        changes.push(Change::Bom);
+    }
+
+    let crlf = normalized.matches("\r\n").count();
+    if crlf > 0 {
+        normalized = Cow::Owned(normalized.replace("\r\n", "\n"));
+        changes.push(Change::CrLf { lines: crlf });
+    }
+
+    // Keep the newline ending the last line, if it has one
+    let last = normalized.trim_end();
+    let end = match normalized[last.len()..].find('\n') {
+        _ if last.is_empty() => 0,
+        Some(newline) => last.len() + newline + 1,
+        None => normalized.len(),
+    };
+    let blank = normalized[end..].matches('\n').count() + !normalized.ends_with('\n') as usize;
+    if end < normalized.len() {
+        changes.push(Change::TrailingBlankLines { lines: blank });
+        normalized = match normalized {
+            Cow::Borrowed(text) => Cow::Borrowed(&text[..end]),
+            Cow::Owned(mut text) => {
+                text.truncate(end);
+                Cow::Owned(text)
+            }
+        };
+    }
+
+    (normalized, changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaves_clean_input_alone() {
+        for contents in ["1\n2\n", "1\n2", "123 \n 45 \n*   \n", ""] {
+            let (normalized, changes) = normalize(contents);
+            assert!(matches!(normalized, Cow::Borrowed(_)));
+            assert_eq!(normalized, contents);
+            assert!(changes.is_empty());
+        }
+    }
+
+    #[test]
+    fn fixes_how_input_was_saved() {
+        let (normalized, changes) = normalize("\u{feff}3-5\r\n10-14\r\n\r\n1\r\n5 \r\n\r\n  \r\n");
+        assert_eq!(normalized, "3-5\n10-14\n\n1\n5 \n");
+        assert_eq!(changes, vec![Change::Bom, Change::CrLf { lines: 7 }, Change::TrailingBlankLines { lines: 2 }]);
+    }
+
+    #[test]
+    fn counts_blank_lines_without_final_newline() {
+        let (normalized, changes) = normalize("1\n2\n\n  ");
+        assert_eq!(normalized, "1\n2\n");
+        assert_eq!(changes, vec![Change::TrailingBlankLines { lines: 2 }]);
+
+        let (normalized, changes) = normalize("\n\n");
+        assert_eq!(normalized, "");
+        assert_eq!(changes, vec![Change::TrailingBlankLines { lines: 2 }]);
+    }
+}
@@ -0,0 +1,126 @@
+//! The integer type answers are computed in, and checked arithmetic on it.
+//!
+//! Answers are checked `i64` by default. The `i128` feature widens them, and `bignum`
+//! makes them arbitrary precision, so the checks can never fail:
+//!
+//! ```text
+//! cargo run --release -p aoc --features bignum -- run --all
+//! ```
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+use std::error::Error;
+use std::fmt;
+
+#[cfg(feature = "bignum")]
+pub type Int = num_bigint::BigInt;
+#[cfg(all(feature = "i128", not(feature = "bignum")))]
+pub type Int = i128;
+#[cfg(not(any(feature = "i128", feature = "bignum")))]
+pub type Int = i64;
+
+/// Name of [`Int`], for messages.
+#[cfg(feature = "bignum")]
+pub const INT_NAME: &str = "bignum";
+#[cfg(all(feature = "i128", not(feature = "bignum")))]
+pub const INT_NAME: &str = "i128";
+#[cfg(not(any(feature = "i128", feature = "bignum")))]
+pub const INT_NAME: &str = "i64";
+
+/// An answer too big for [`Int`].
+///
+/// `day` and `part` are filled in by [`Puzzle::solve`](crate::Puzzle::solve), like
+/// [`ParseError`](crate::ParseError)'s `day`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub day: u32,
+    pub part: u8,
+    /// The operation that overflowed, e.g. `"9223372036854775807 + 1"`.
+    pub expression: String,
+}
+
+impl Overflow {
+    fn new(a: &Int, op: char, b: &Int) -> Self {
+        Overflow { day: 0, part: 0, expression: format!("{} {} {}", a, op, b) }
+    }
+
+    pub fn on_part(mut self, day: u32, part: u8) -> Self {
+        self.day = day;
+        self.part = part;
+        self
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {} part {}: {} overflows {}; build with `--features i128` or `--features bignum`",
+            self.day, self.part, self.expression, INT_NAME
+        )
+    }
+}
+
+impl Error for Overflow {}
+
+/// `n` as an [`Int`].
+#[allow(clippy::useless_conversion)]
+pub fn int(n: i64) -> Int {
+    Int::from(n)
+}
+
+pub fn add(a: &Int, b: &Int) -> Result<Int, Overflow> {
+    CheckedAdd::checked_add(a, b).ok_or_else(|| Overflow::new(a, '+', b))
+}
+
+pub fn sub(a: &Int, b: &Int) -> Result<Int, Overflow> {
+    CheckedSub::checked_sub(a, b).ok_or_else(|| Overflow::new(a, '-', b))
+}
+
+pub fn mul(a: &Int, b: &Int) -> Result<Int, Overflow> {
+    CheckedMul::checked_mul(a, b).ok_or_else(|| Overflow::new(a, '*', b))
+}
+
+pub fn sum(values: impl IntoIterator<Item = Int>) -> Result<Int, Overflow> {
+    values.into_iter().try_fold(int(0), |total, value| add(&total, &value))
+}
+
+pub fn product(values: impl IntoIterator<Item = Int>) -> Result<Int, Overflow> {
+    values.into_iter().try_fold(int(1), |total, value| mul(&total, &value))
+}
+
+/// [`sum`] of values that can themselves overflow.
+pub fn try_sum(values: impl IntoIterator<Item = Result<Int, Overflow>>) -> Result<Int, Overflow> {
+    values.into_iter().try_fold(int(0), |total, value| add(&total, &value?))
+}
+
+/// [`product`] of values that can themselves overflow.
+pub fn try_product(values: impl IntoIterator<Item = Result<Int, Overflow>>) -> Result<Int, Overflow> {
+    values.into_iter().try_fold(int(1), |total, value| mul(&total, &value?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_arithmetic() {
+        assert_eq!(sum([int(1), int(2), int(3)]), Ok(int(6)));
+        assert_eq!(product([int(4), int(5)]), Ok(int(20)));
+        assert_eq!(sub(&int(3), &int(5)), Ok(int(-2)));
+        assert_eq!(try_sum([Ok(int(1)), mul(&int(2), &int(3))]), Ok(int(7)));
+    }
+
+    #[cfg(not(any(feature = "i128", feature = "bignum")))]
+    #[test]
+    fn detects_overflow() {
+        let overflow = add(&int(i64::MAX), &int(1)).unwrap_err();
+        assert_eq!(overflow.expression, "9223372036854775807 + 1");
+        assert!(product([int(1 << 40), int(1 << 40)]).is_err());
+    }
+
+    #[cfg(any(feature = "i128", feature = "bignum"))]
+    #[test]
+    fn widens_past_i64() {
+        assert_eq!(product([int(1 << 40), int(1 << 40)]).unwrap().to_string(), "1208925819614629174706176");
+    }
+}
@@ -0,0 +1,120 @@
+//! Puzzle constants that can be changed without editing the solvers.
+//!
+//! Each day's [`Solution::Params`](crate::Solution::Params) holds its constants, defaulting
+//! to the puzzle's own values. Overrides come in as a TOML table, merged from the
+//! `[params.N]` table of `aoc.toml` and `--param key=value` flags, and are checked
+//! against the day's type when it's solved.
+//!
+//! ```toml
+//! [params.8]
+//! num_pairs = 10
+//!
+//! [params.11]
+//! start = "svr"
+//! ```
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+/// Overrides for some of a day's parameters, by field name.
+pub type ParamTable = toml::Table;
+pub type ParamValue = toml::Value;
+
+/// The `[params.N]` tables of `aoc.toml`, by day.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(transparent)]
+pub struct ParamOverrides(HashMap<String, ParamTable>);
+
+impl ParamOverrides {
+    /// The overrides for `day`, empty if it has none.
+    pub fn day(&self, day: u32) -> ParamTable {
+        self.0.get(&day.to_string()).cloned().unwrap_or_default()
+    }
+}
+
+/// Params for a day with nothing to tune.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+/// Overrides that don't fit the day's parameters, like an unknown name or a string for a number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamsError {
+    pub day: u32,
+    pub message: String,
+}
+
+impl fmt::Display for ParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {} parameters: {}", self.day, self.message)
+    }
+}
+
+impl Error for ParamsError {}
+
+/// The day's defaults with `overrides` applied.
+pub fn resolve<P: Default + DeserializeOwned>(day: u32, overrides: &ParamTable) -> Result<P, ParamsError> {
+    if overrides.is_empty() {
+        return Ok(P::default());
+    }
+    P::deserialize(ParamValue::Table(overrides.clone()))
+        .map_err(|err| ParamsError { day, message: err.message().to_string() })
+}
+
+/// Splits a `key=value` override. The value is read as TOML if it can be, so numbers and
+/// lists work, and as a plain string otherwise, so `start=svr` needs no quotes.
+pub fn parse_override(text: &str) -> Result<(String, ParamValue), String> {
+    let (key, value) = text.split_once('=').ok_or_else(|| format!("expected key=value, found {:?}", text))?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(format!("expected a parameter name before '=' in {:?}", text));
+    }
+
+    let value = value.trim();
+    let value = match format!("value = {}", value).parse::<ParamTable>() {
+        Ok(mut table) => table.remove("value").unwrap(),
+        Err(_) => ParamValue::String(value.to_string()),
+    };
+    Ok((key.to_string(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Dial {
+        start: i64,
+        name: String,
+    }
+
+    impl Default for Dial {
+        fn default() -> Self {
+            Dial { start: 50, name: "dial".to_string() }
+        }
+    }
+
+    fn overrides(pairs: &[&str]) -> ParamTable {
+        pairs.iter().map(|pair| parse_override(pair).unwrap()).collect()
+    }
+
+    #[test]
+    fn overrides_defaults() {
+        assert_eq!(resolve::<Dial>(1, &ParamTable::new()), Ok(Dial::default()));
+        assert_eq!(resolve::<Dial>(1, &overrides(&["start=7"])), Ok(Dial { start: 7, name: "dial".to_string() }));
+        assert_eq!(resolve::<Dial>(1, &overrides(&["name = safe"])), Ok(Dial { start: 50, name: "safe".to_string() }));
+    }
+
+    #[test]
+    fn rejects_bad_overrides() {
+        assert!(resolve::<Dial>(1, &overrides(&["start=seven"])).is_err());
+        assert!(resolve::<Dial>(1, &overrides(&["stop=7"])).unwrap_err().message.contains("stop"));
+        assert!(resolve::<NoParams>(4, &overrides(&["size=3"])).is_err());
+        assert!(parse_override("start").is_err());
+        assert!(parse_override("=7").is_err());
+    }
+}
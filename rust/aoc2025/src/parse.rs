@@ -0,0 +1,193 @@
+use crate::error::Unsupported;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed puzzle input, pointing at the offending text.
+///
+/// Lines and columns are 1-based. `day` is filled in by [`Puzzle::solve`](crate::Puzzle::solve),
+/// so the day crates don't need to track it themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, expected: impl Into<String>) -> Self {
+        ParseError {
+            day: 0,
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Error for input that ends before something required, e.g. a missing section.
+    pub fn end_of_input(contents: &str, expected: impl Into<String>) -> Self {
+        ParseError::new(contents.lines().count() + 1, 1, "", expected)
+    }
+
+    pub fn on_day(mut self, day: u32) -> Self {
+        self.day = day;
+        self
+    }
+
+    /// Multi-line diagnostic quoting the offending line of `contents`, for printing to a terminal.
+    pub fn render(&self, path: &str, contents: &str) -> String {
+        quote(self, path, contents, self.line, self.column, &self.text)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {}, line {}, column {}: expected {}, ", self.day, self.line, self.column, self.expected)?;
+        if self.text.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found {:?}", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// `message` followed by the line of `contents` it's about, with `text` underlined.
+pub(crate) fn quote(message: &dyn fmt::Display, path: &str, contents: &str, line: usize, column: usize, text: &str) -> String {
+    let gutter = line.to_string().len();
+    let source = contents.lines().nth(line - 1).unwrap_or("");
+    let width = text.chars().count().max(1);
+
+    format!(
+        "error: {}\n{:gutter$}--> {}:{}:{}\n{:gutter$} |\n{} | {}\n{:gutter$} | {}{}",
+        message,
+        "",
+        path,
+        line,
+        column,
+        "",
+        line,
+        source,
+        "",
+        " ".repeat(source.chars().take(column - 1).count()),
+        "^".repeat(width),
+        gutter = gutter,
+    )
+}
+
+/// One line of puzzle input, numbered from 1.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// 1-based column where `part` starts. `part` should be a slice of this line;
+    /// anything else is reported at column 1.
+    pub fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        self.text.get(..offset).map_or(1, |before| before.chars().count() + 1)
+    }
+
+    pub fn error(&self, part: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column_of(part), part, expected)
+    }
+
+    /// Error pointing just past the end of the line.
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error(&self.text[self.text.len()..], expected)
+    }
+
+    pub fn parse<T: FromStr>(&self, part: &str, expected: &str) -> Result<T, ParseError> {
+        part.parse::<T>().map_err(|_| self.error(part, expected))
+    }
+
+    /// Checks every character of `part` against `valid`, pointing at the first one that isn't.
+    pub fn check_chars(&self, part: &str, valid: impl Fn(char) -> bool, expected: &str) -> Result<(), ParseError> {
+        match part.char_indices().find(|&(_, c)| !valid(c)) {
+            Some((ix, c)) => Err(self.error(&part[ix..ix + c.len_utf8()], expected)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn split_once(&self, part: &'a str, delimiter: char) -> Result<(&'a str, &'a str), ParseError> {
+        part.split_once(delimiter)
+            .ok_or_else(|| self.error(part, format!("'{}'", delimiter)))
+    }
+
+    /// Where `part` is, for reporting problems with it after parsing.
+    pub fn span(&self, part: &str) -> Span {
+        Span { line: self.number, column: self.column_of(part), text: part.to_string() }
+    }
+}
+
+/// A piece of the input kept in a parsed model, so a part that finds something wrong
+/// with it (like an unsolvable machine) can still point at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+impl Span {
+    /// Just past the last line, for things that are missing altogether.
+    pub fn end_of_input(contents: &str) -> Self {
+        Span { line: contents.lines().count() + 1, column: 1, text: String::new() }
+    }
+
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, self.column, &self.text, expected)
+    }
+
+    /// For well-formed input the solver can't handle, like a region too big to search.
+    pub fn unsupported(&self, reason: impl Into<String>) -> Unsupported {
+        Unsupported { day: 0, part: 0, span: self.clone(), reason: reason.into() }
+    }
+}
+
+/// The lines of `contents`, numbered for error reporting.
+pub fn lines(contents: &str) -> impl Iterator<Item = Line<'_>> + Clone {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(ix, text)| Line { number: ix + 1, text })
+}
+
+/// Like [`lines`], but for a section that starts partway through `contents`.
+pub fn section_lines<'a>(contents: &'a str, section: &'a str) -> impl Iterator<Item = Line<'a>> + Clone {
+    let offset = (section.as_ptr() as usize).wrapping_sub(contents.as_ptr() as usize);
+    let first = contents.get(..offset).map_or(0, |before| before.matches('\n').count());
+    section
+        .lines()
+        .enumerate()
+        .map(move |(ix, text)| Line { number: first + ix + 1, text })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_point_into_line() {
+        let line = lines("abc\n12,x4").nth(1).unwrap();
+        let (_, rest) = line.split_once(line.text, ',').unwrap();
+        let err = line.parse::<i64>(rest, "a number").unwrap_err();
+
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "x4"));
+    }
+
+    #[test]
+    fn section_lines_are_numbered_from_contents() {
+        let contents = "1-2\n\n7\n8";
+        let (_, ids) = contents.split_once("\n\n").unwrap();
+
+        assert_eq!(section_lines(contents, ids).map(|line| line.number).collect::<Vec<_>>(), vec![3, 4]);
+    }
+}
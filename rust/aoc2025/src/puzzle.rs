@@ -0,0 +1,241 @@
+use crate::bench::{self, BenchOptions, BenchResult, Stage};
+use crate::memo::{self, MemoStats};
+use crate::params::{self, ParamTable};
+use crate::{Answer, Example, ParseError, Records, Rng, SolveError, Solution};
+use std::any::Any;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Answer and timing for a single part of a day.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub day: u32,
+    pub part: u8,
+    pub label: &'static str,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    /// How the part's memo tables did, if it used any.
+    pub memo: Vec<MemoStats>,
+}
+
+impl fmt::Display for PartResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\nPart {}:\n", self.part)?;
+        if !self.label.is_empty() {
+            write!(f, "{}: ", self.label)?;
+        }
+        if matches!(self.answer, Answer::Grid(_)) {
+            writeln!(f)?;
+        }
+        write!(f, "{}\nRan in {:.5?}", self.answer, self.elapsed)
+    }
+}
+
+/// A day's requested parts, with the time spent on the parse they share.
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub parse: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// A day's input parsed into its model, whose parts can then be run one at a time,
+/// e.g. from another language where the model's type can't be named.
+pub struct Parsed {
+    puzzle: Puzzle,
+    input: Box<dyn Any + Send + Sync>,
+    pub parse: Duration,
+}
+
+impl Parsed {
+    pub fn day(&self) -> u32 {
+        self.puzzle.day
+    }
+
+    /// Runs `part` (1 or 2) on the parsed model.
+    pub fn part(&self, part: u8) -> Result<PartResult, SolveError> {
+        (self.puzzle.part)(&self.puzzle, self.input.as_ref(), part)
+    }
+}
+
+impl fmt::Debug for Parsed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Parsed")
+            .field("day", &self.puzzle.day)
+            .field("parse", &self.parse)
+            .finish()
+    }
+}
+
+type SolveFn = fn(&Puzzle, &str, &[u8], &ParamTable) -> Result<Solved, SolveError>;
+type ParseFn = fn(&Puzzle, &str, &ParamTable) -> Result<Box<dyn Any + Send + Sync>, SolveError>;
+type PartFn = fn(&Puzzle, &dyn Any, u8) -> Result<PartResult, SolveError>;
+type BenchFn = fn(&Puzzle, &str, &[u8], &ParamTable, &BenchOptions) -> Result<Vec<BenchResult>, SolveError>;
+
+/// A day's `Solution` with its types erased, so every day can live in one table.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub day: u32,
+    pub title: &'static str,
+    pub labels: [&'static str; 2],
+    pub examples: &'static [Example],
+    pub records: Option<Records>,
+    solve: SolveFn,
+    parse: ParseFn,
+    part: PartFn,
+    bench: BenchFn,
+    reference: fn(&str, u8) -> Answer,
+    lint: fn(&str) -> Vec<ParseError>,
+    generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Puzzle {
+    pub const fn of<S: Solution>() -> Self {
+        Puzzle {
+            day: S::DAY,
+            title: S::TITLE,
+            labels: S::LABELS,
+            examples: S::EXAMPLES,
+            records: S::RECORDS,
+            solve: solve::<S>,
+            parse: parse::<S>,
+            part: erased_part::<S>,
+            bench: bench::<S>,
+            reference: reference::<S>,
+            lint: S::lint,
+            generate: S::generate,
+        }
+    }
+
+    /// Parses `contents` once and runs the requested parts (1 and/or 2) in order,
+    /// stopping at the first malformed input or overflowing answer.
+    pub fn solve(&self, contents: &str, parts: &[u8]) -> Result<Solved, SolveError> {
+        (self.solve)(self, contents, parts, &ParamTable::new())
+    }
+
+    /// [`solve`](Self::solve) with some of the day's [`params`] overridden.
+    pub fn solve_with(&self, contents: &str, parts: &[u8], params: &ParamTable) -> Result<Solved, SolveError> {
+        (self.solve)(self, contents, parts, params)
+    }
+
+    /// Parses `contents` without running either part, for running them one at a time
+    /// with [`Parsed::part`].
+    pub fn parse(&self, contents: &str, params: &ParamTable) -> Result<Parsed, SolveError> {
+        let timer = Instant::now();
+        let input = (self.parse)(self, contents, params)?;
+        Ok(Parsed { puzzle: *self, input, parse: timer.elapsed() })
+    }
+
+    /// Times parsing and each requested part separately, over repeated runs.
+    pub fn bench(&self, contents: &str, parts: &[u8], params: &ParamTable, options: &BenchOptions) -> Result<Vec<BenchResult>, SolveError> {
+        (self.bench)(self, contents, parts, params, options)
+    }
+
+    /// The reference solver's answer to `part`, for checking `solve` against.
+    pub fn reference(&self, contents: &str, part: u8) -> Answer {
+        (self.reference)(contents, part)
+    }
+
+    /// Every broken assumption the day's solver makes about `contents`, in the order they appear.
+    pub fn lint(&self, contents: &str) -> Vec<ParseError> {
+        let mut problems: Vec<ParseError> = (self.lint)(contents).into_iter().map(|err| err.on_day(self.day)).collect();
+        problems.sort_by_key(|err| (err.line, err.column));
+        problems
+    }
+
+    /// Random input of roughly `size` items. The same seed and size always give the same input.
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+impl fmt::Debug for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Puzzle")
+            .field("day", &self.day)
+            .field("title", &self.title)
+            .finish()
+    }
+}
+
+fn solve<S: Solution>(puzzle: &Puzzle, contents: &str, parts: &[u8], params: &ParamTable) -> Result<Solved, SolveError> {
+    let params: S::Params = params::resolve(puzzle.day, params)?;
+    let timer = Instant::now();
+    let input = S::parse_with(contents, &params).map_err(|err| SolveError::Parse(err.on_day(puzzle.day)))?;
+    let parse = timer.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| run_part::<S>(puzzle, &input, part))
+        .collect::<Result<_, SolveError>>()?;
+
+    Ok(Solved { parse, parts })
+}
+
+fn run_part<S: Solution>(puzzle: &Puzzle, input: &S::Input, part: u8) -> Result<PartResult, SolveError> {
+    memo::take_stats();
+    let timer = Instant::now();
+    let answer = match part {
+        1 => S::part1(input).map(Into::into),
+        2 => S::part2(input).map(Into::into),
+        _ => panic!("Unknown part: {}", part),
+    }
+    .map_err(|err| err.on_part(puzzle.day, part))?;
+
+    Ok(PartResult {
+        day: puzzle.day,
+        part,
+        label: puzzle.labels[part as usize - 1],
+        answer,
+        elapsed: timer.elapsed(),
+        memo: memo::take_stats(),
+    })
+}
+
+fn parse<S: Solution>(puzzle: &Puzzle, contents: &str, params: &ParamTable) -> Result<Box<dyn Any + Send + Sync>, SolveError> {
+    let params: S::Params = params::resolve(puzzle.day, params)?;
+    let input = S::parse_with(contents, &params).map_err(|err| SolveError::Parse(err.on_day(puzzle.day)))?;
+    Ok(Box::new(input))
+}
+
+fn erased_part<S: Solution>(puzzle: &Puzzle, input: &dyn Any, part: u8) -> Result<PartResult, SolveError> {
+    let input = input.downcast_ref::<S::Input>().expect("Parsed input should be the puzzle's own model");
+    run_part::<S>(puzzle, input, part)
+}
+
+fn reference<S: Solution>(contents: &str, part: u8) -> Answer {
+    match part {
+        1 => S::reference1(contents).into(),
+        2 => S::reference2(contents).into(),
+        _ => panic!("Unknown part: {}", part),
+    }
+}
+
+fn bench<S: Solution>(puzzle: &Puzzle, contents: &str, parts: &[u8], params: &ParamTable, options: &BenchOptions) -> Result<Vec<BenchResult>, SolveError> {
+    let params: S::Params = params::resolve(puzzle.day, params)?;
+    let input = S::parse_with(contents, &params).map_err(|err| SolveError::Parse(err.on_day(puzzle.day)))?;
+    let mut results = vec![BenchResult {
+        day: puzzle.day,
+        stage: Stage::Parse,
+        stats: bench::measure(options, || S::parse_with(contents, &params)),
+    }];
+
+    for &part in parts {
+        // Run once up front so malformed input is reported instead of timed
+        let stats = match part {
+            1 => {
+                S::part1(&input).map_err(|err| err.on_part(puzzle.day, part))?;
+                bench::measure(options, || S::part1(&input))
+            }
+            2 => {
+                S::part2(&input).map_err(|err| err.on_part(puzzle.day, part))?;
+                bench::measure(options, || S::part2(&input))
+            }
+            _ => panic!("Unknown part: {}", part),
+        };
+
+        results.push(BenchResult { day: puzzle.day, stage: Stage::part(part), stats });
+    }
+    memo::take_stats();
+
+    Ok(results)
+}
@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_2025_1::Day1;
+
+fuzz_target!(|data: &[u8]| aoc2025_fuzz::solve::<Day1>(data));
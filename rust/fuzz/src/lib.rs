@@ -0,0 +1,10 @@
+use aoc2025::{Puzzle, Solution};
+
+/// Runs both parts on `data`, if it's text. Malformed input should come back as a
+/// `ParseError` and answers too big for `Int` as an `Overflow`; any panic is left to
+/// fail the fuzz target.
+pub fn solve<S: Solution>(data: &[u8]) {
+    if let Ok(contents) = std::str::from_utf8(data) {
+        let _ = Puzzle::of::<S>().solve(contents, &[1, 2]);
+    }
+}
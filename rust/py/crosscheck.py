@@ -0,0 +1,112 @@
+"""Solves each day with both the Python solution in python/N.py and the Rust one through
+the aoc2025_rs extension, and reports every part where their answers differ.
+
+    python crosscheck.py              # every day with an input
+    python crosscheck.py 7 8 --inputs ../../../Inputs
+
+Also usable from a notebook: `crosscheck(7, "Inputs/2025_7.txt")` gives each part's pair
+of answers. Each side's part1 and part2 are called directly on the same text, so one
+raising doesn't stop the other part being checked.
+"""
+
+from __future__ import annotations
+
+import argparse
+import importlib.util
+import os
+import sys
+from pathlib import Path
+from typing import Any, List, Tuple
+
+import aoc2025_rs
+
+YEAR = 2025
+PYTHON_DIR = Path(__file__).resolve().parent.parent.parent / "python"
+# Where the Python solutions look for inputs themselves
+DEFAULT_INPUTS = PYTHON_DIR.parent.parent / "Inputs"
+
+
+def python_solution(day: int):
+    """Imports python/{day}.py, which needs whatever it imports, like z3 for day 10."""
+    spec = importlib.util.spec_from_file_location(f"python_{day}", PYTHON_DIR / f"{day}.py")
+    module = importlib.util.module_from_spec(spec)
+    spec.loader.exec_module(module)
+    return module
+
+
+class Failed:
+    """A solution that raised instead of answering, which never agrees with anything."""
+
+    def __init__(self, err: BaseException):
+        self.err = err
+
+    def __str__(self) -> str:
+        return f"raised {type(self.err).__name__}: {self.err}"
+
+
+def crosscheck(day: int, input_path: Path | str) -> List[Tuple[int, Any, Any]]:
+    """(part, Python answer, Rust answer) for both parts of `day` on the input at `input_path`,
+    with `Failed` in place of an answer for a part that raised."""
+    contents = Path(input_path).read_text(encoding="UTF-8")
+    solution = python_solution(day)
+    data = contents.splitlines()
+
+    try:
+        parsed = aoc2025_rs.parse(day, contents)
+    except ValueError as err:
+        parsed = Failed(err)
+
+    results = []
+    for part, (python_part, rust_part) in enumerate([(solution.part1, aoc2025_rs.part1), (solution.part2, aoc2025_rs.part2)], 1):
+        answers = []
+        for solve, model in [(python_part, data), (rust_part, parsed)]:
+            try:
+                answers.append(model if isinstance(model, Failed) else solve(model))
+            except (KeyboardInterrupt, SystemExit):
+                raise
+            except BaseException as err:  # including a Rust panic, which isn't an Exception
+                answers.append(Failed(err))
+        results.append((part, *answers))
+    return results
+
+
+def agree(python_answer: Any, rust_answer: Any) -> bool:
+    """Answers are compared as text, so e.g. a Python str of digits still matches a Rust int."""
+    if isinstance(python_answer, Failed) or isinstance(rust_answer, Failed):
+        return False
+    return str(python_answer) == str(rust_answer)
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser(description="Check the Python solutions against the Rust ones.")
+    parser.add_argument("days", nargs="*", type=int, help="days to check (default: all)")
+    parser.add_argument("--inputs", type=Path, default=Path(os.environ.get("AOC_INPUT_DIR", DEFAULT_INPUTS)), help="directory of {year}_{day}.txt inputs")
+    args = parser.parse_args()
+
+    disagreements = 0
+    for day in args.days or aoc2025_rs.days():
+        input_path = args.inputs / f"{YEAR}_{day}.txt"
+        if not input_path.is_file():
+            print(f"Day {day}: skipped, no input at {input_path}")
+            continue
+
+        try:
+            results = crosscheck(day, input_path)
+        except ImportError as err:
+            print(f"Day {day}: skipped, the Python solution needs {err.name}")
+            continue
+
+        for part, python_answer, rust_answer in results:
+            if agree(python_answer, rust_answer):
+                print(f"Day {day} part {part}: both {rust_answer}")
+            else:
+                disagreements += 1
+                print(f"Day {day} part {part}: Python {python_answer}; Rust {rust_answer}")
+
+    if disagreements:
+        print(f"\n{disagreements} parts disagree")
+    return 1 if disagreements else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,162 @@
+//! The Rust solvers as a Python extension module, `aoc2025_rs`. Build it with
+//! `maturin develop --release` from this directory.
+
+use aoc2025::params::{ParamTable, ParamValue};
+use aoc2025::{Answer, Input, SolveError};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyNotImplementedError, PyOverflowError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyList, PyString};
+
+create_exception!(aoc2025_rs, ParseError, PyValueError, "The input isn't in the format the day's solver expects.");
+
+/// A day's input parsed into its model, ready for `part1` and `part2`.
+#[pyclass(frozen, module = "aoc2025_rs")]
+struct Parsed {
+    parsed: aoc2025::Parsed,
+    /// The normalized input, for pointing at where a part's error is.
+    contents: String,
+}
+
+#[pymethods]
+impl Parsed {
+    #[getter]
+    fn day(&self) -> u32 {
+        self.parsed.day()
+    }
+
+    /// Seconds spent parsing.
+    #[getter]
+    fn parse_seconds(&self) -> f64 {
+        self.parsed.parse.as_secs_f64()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<aoc2025_rs.Parsed day {}>", self.parsed.day())
+    }
+}
+
+fn puzzle(day: u32) -> PyResult<&'static aoc2025::Puzzle> {
+    aoc::puzzle(day).ok_or_else(|| PyValueError::new_err(format!("No solver for day {}", day)))
+}
+
+fn error(err: SolveError, contents: &str) -> PyErr {
+    match err {
+        SolveError::Parse(_) => ParseError::new_err(err.render("<input>", contents)),
+        SolveError::Overflow(_) => PyOverflowError::new_err(err.to_string()),
+        SolveError::Unsupported(_) => PyNotImplementedError::new_err(err.render("<input>", contents)),
+        SolveError::Params(_) => PyValueError::new_err(err.to_string()),
+    }
+}
+
+/// A Python value as a parameter override, the way it would be written in `aoc.toml`.
+fn param_value(value: &Bound<'_, PyAny>) -> PyResult<ParamValue> {
+    if value.is_instance_of::<PyBool>() {
+        Ok(ParamValue::Boolean(value.extract()?))
+    } else if let Ok(n) = value.extract::<i64>() {
+        Ok(ParamValue::Integer(n))
+    } else if let Ok(x) = value.extract::<f64>() {
+        Ok(ParamValue::Float(x))
+    } else if let Ok(text) = value.extract::<String>() {
+        Ok(ParamValue::String(text))
+    } else if let Ok(list) = value.cast::<PyList>() {
+        list.iter().map(|item| param_value(&item)).collect::<PyResult<_>>().map(ParamValue::Array)
+    } else {
+        Err(PyValueError::new_err(format!("Can't use {} as a parameter", value.repr()?)))
+    }
+}
+
+fn param_table(params: Option<&Bound<'_, PyDict>>) -> PyResult<ParamTable> {
+    let mut table = ParamTable::new();
+    for (key, value) in params.into_iter().flat_map(|params| params.iter()) {
+        table.insert(key.extract()?, param_value(&value)?);
+    }
+    Ok(table)
+}
+
+fn answer<'py>(py: Python<'py>, answer: Answer) -> PyResult<Bound<'py, PyAny>> {
+    Ok(match answer {
+        Answer::Signed(n) => n.into_pyobject(py)?.into_any(),
+        Answer::Unsigned(n) => n.into_pyobject(py)?.into_any(),
+        Answer::Big(n) => n.into_pyobject(py)?.into_any(),
+        Answer::Text(text) => PyString::new(py, &text).into_any(),
+        Answer::Grid(rows) => PyString::new(py, &rows.join("\n")).into_any(),
+    })
+}
+
+fn run_part<'py>(py: Python<'py>, parsed: &Parsed, part: u8) -> PyResult<Bound<'py, PyAny>> {
+    let result = py.detach(|| parsed.parsed.part(part)).map_err(|err| error(err, &parsed.contents))?;
+    answer(py, result.answer)
+}
+
+/// Days with a solver.
+#[pyfunction]
+fn days() -> Vec<u32> {
+    aoc::PUZZLES.iter().map(|puzzle| puzzle.day).collect()
+}
+
+/// The puzzle's title, e.g. "Playground" for day 8.
+#[pyfunction]
+fn title(day: u32) -> PyResult<&'static str> {
+    Ok(puzzle(day)?.title)
+}
+
+/// Parses `contents` for `day`, with any of the day's parameters in `params` overridden.
+/// Raises `ParseError` pointing at the first malformed line.
+#[pyfunction]
+#[pyo3(signature = (day, contents, params = None))]
+fn parse(py: Python<'_>, day: u32, contents: &str, params: Option<&Bound<'_, PyDict>>) -> PyResult<Parsed> {
+    let puzzle = puzzle(day)?;
+    let params = param_table(params)?;
+    let contents = Input::new(String::new(), contents.to_string()).contents;
+    match py.detach(|| puzzle.parse(&contents, &params)) {
+        Ok(parsed) => Ok(Parsed { parsed, contents }),
+        Err(err) => Err(error(err, &contents)),
+    }
+}
+
+/// Part 1's answer for a parsed input.
+#[pyfunction]
+fn part1<'py>(py: Python<'py>, parsed: &Parsed) -> PyResult<Bound<'py, PyAny>> {
+    run_part(py, parsed, 1)
+}
+
+/// Part 2's answer for a parsed input.
+#[pyfunction]
+fn part2<'py>(py: Python<'py>, parsed: &Parsed) -> PyResult<Bound<'py, PyAny>> {
+    run_part(py, parsed, 2)
+}
+
+/// Both parts' answers for `contents`, parsing it once.
+#[pyfunction]
+#[pyo3(signature = (day, contents, params = None))]
+fn solve<'py>(py: Python<'py>, day: u32, contents: &str, params: Option<&Bound<'_, PyDict>>) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+    let parsed = parse(py, day, contents, params)?;
+    Ok((run_part(py, &parsed, 1)?, run_part(py, &parsed, 2)?))
+}
+
+/// Each day's Rust parser and parts.
+///
+/// ```python
+/// import aoc2025_rs
+///
+/// parsed = aoc2025_rs.parse(7, open("Inputs/2025_7.txt").read())
+/// aoc2025_rs.part1(parsed), aoc2025_rs.part2(parsed)
+/// aoc2025_rs.solve(8, contents, {"num_pairs": 10})
+/// ```
+///
+/// Inputs are normalized the same way the `aoc` command does before they're parsed.
+/// Integer answers come back as ints of any size, text as str and grids as one str with
+/// a row per line, so they compare directly with the Python solutions' answers.
+#[pymodule]
+fn aoc2025_rs(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<Parsed>()?;
+    module.add("ParseError", module.py().get_type::<ParseError>())?;
+    module.add_function(wrap_pyfunction!(days, module)?)?;
+    module.add_function(wrap_pyfunction!(title, module)?)?;
+    module.add_function(wrap_pyfunction!(parse, module)?)?;
+    module.add_function(wrap_pyfunction!(part1, module)?)?;
+    module.add_function(wrap_pyfunction!(part2, module)?)?;
+    module.add_function(wrap_pyfunction!(solve, module)?)?;
+    Ok(())
+}